@@ -1,31 +1,41 @@
-use core::slice::from_raw_parts;
-
-use solana_account_view::AccountView;
-use solana_address::Address;
-use solana_instruction_view::{
-    cpi::{invoke_signed, Signer},
-    InstructionAccount, InstructionView,
+use {
+    crate::{instructions::MAX_MULTISIG_SIGNERS, write_bytes, UNINIT_BYTE},
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
 };
-use solana_program_error::ProgramResult;
-
-use crate::{write_bytes, UNINIT_BYTE};
 
 /// Approves a delegate.
 ///
 /// ### Accounts:
+///   * Single authority
 ///   0. `[WRITE]` The source account.
 ///   1. `[]` The token mint.
 ///   2. `[]` The delegate.
 ///   3. `[SIGNER]` The source account owner.
-pub struct ApproveChecked<'a, 'b> {
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[]` The delegate.
+///   3. `[]` The source account's multisignature owner.
+///   4. ..4+M `[SIGNER]` M signer accounts.
+pub struct ApproveChecked<'a, 'b, 'c> {
     /// Source Account.
     pub source: &'a AccountView,
     /// Mint Account.
     pub mint: &'a AccountView,
     /// Delegate Account.
     pub delegate: &'a AccountView,
-    /// Source Owner Account.
+    /// Source Owner Account (single or multisig).
     pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
     /// Amount.
     pub amount: u64,
     /// Decimals.
@@ -34,7 +44,7 @@ pub struct ApproveChecked<'a, 'b> {
     pub token_program: &'b Address,
 }
 
-impl ApproveChecked<'_, '_> {
+impl ApproveChecked<'_, '_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
         self.invoke_signed(&[])
@@ -42,13 +52,54 @@ impl ApproveChecked<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        // Instruction accounts
-        let instruction_accounts: [InstructionAccount; 4] = [
-            InstructionAccount::writable(self.source.address()),
-            InstructionAccount::readonly(self.mint.address()),
-            InstructionAccount::readonly(self.delegate.address()),
-            InstructionAccount::readonly_signer(self.authority.address()),
-        ];
+        let &Self {
+            source,
+            mint,
+            delegate,
+            authority,
+            signers: multisig_accounts,
+            amount,
+            decimals,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 3 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(source.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::readonly(mint.address()));
+            instruction_accounts
+                .get_unchecked_mut(2)
+                .write(InstructionAccount::readonly(delegate.address()));
+            instruction_accounts
+                .get_unchecked_mut(3)
+                .write(InstructionAccount::new(
+                    authority.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+        }
+
+        // Fill signer accounts
+        for (instruction_account, signer) in instruction_accounts[4..]
+            .iter_mut()
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
 
         // Instruction data
         // -  [0]  : instruction discriminator (1 byte, u8)
@@ -59,19 +110,44 @@ impl ApproveChecked<'_, '_> {
         // Set discriminator as u8 at offset [0]
         write_bytes(&mut instruction_data, &[13]);
         // Set amount as u64 at offset [1..9]
-        write_bytes(&mut instruction_data[1..9], &self.amount.to_le_bytes());
+        write_bytes(&mut instruction_data[1..9], &amount.to_le_bytes());
         // Set decimals as u8 at offset [9]
-        write_bytes(&mut instruction_data[9..], &[self.decimals]);
+        write_bytes(&mut instruction_data[9..], &[decimals]);
+
+        let num_accounts = 4 + multisig_accounts.len();
 
         let instruction = InstructionView {
-            program_id: self.token_program,
-            accounts: &instruction_accounts,
-            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 10) },
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 10) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
         };
 
-        invoke_signed(
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 3 are always present
+            account_views.get_unchecked_mut(0).write(source);
+            account_views.get_unchecked_mut(1).write(mint);
+            account_views.get_unchecked_mut(2).write(delegate);
+            account_views.get_unchecked_mut(3).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_view, signer) in account_views[4..].iter_mut().zip(multisig_accounts.iter()) {
+            account_view.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
             &instruction,
-            &[self.source, self.mint, self.delegate, self.authority],
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
             signers,
         )
     }
@@ -1,35 +1,44 @@
-use core::slice::from_raw_parts;
-
-use solana_account_view::AccountView;
-use solana_address::Address;
-use solana_instruction_view::{
-    cpi::{invoke_signed, Signer},
-    InstructionAccount, InstructionView,
+use {
+    crate::{instructions::MAX_MULTISIG_SIGNERS, write_bytes, UNINIT_BYTE},
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
 };
-use solana_program_error::ProgramResult;
-
-use crate::{write_bytes, UNINIT_BYTE};
 
 /// Burns tokens by removing them from an account.
 ///
 /// ### Accounts:
+///   * Single authority
 ///   0. `[WRITE]` The account to burn from.
 ///   1. `[WRITE]` The token mint.
 ///   2. `[SIGNER]` The account's owner/delegate.
-pub struct Burn<'a, 'b> {
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The account to burn from.
+///   1. `[WRITE]` The token mint.
+///   2. `[]` The account's multisignature owner/delegate.
+///   3. ..3+M `[SIGNER]` M signer accounts.
+pub struct Burn<'a, 'b, 'c> {
     /// Source of the Burn Account
     pub account: &'a AccountView,
     /// Mint Account
     pub mint: &'a AccountView,
-    /// Owner of the Token Account
+    /// Owner of the Token Account (single or multisig).
     pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
     /// Amount
     pub amount: u64,
     /// Token Program
     pub token_program: &'b Address,
 }
 
-impl Burn<'_, '_> {
+impl Burn<'_, '_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
         self.invoke_signed(&[])
@@ -37,12 +46,49 @@ impl Burn<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        // Instruction accounts
-        let instruction_accounts: [InstructionAccount; 3] = [
-            InstructionAccount::writable(self.account.address()),
-            InstructionAccount::writable(self.mint.address()),
-            InstructionAccount::readonly_signer(self.authority.address()),
-        ];
+        let &Self {
+            account,
+            mint,
+            authority,
+            signers: multisig_accounts,
+            amount,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 2 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(account.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::writable(mint.address()));
+            instruction_accounts
+                .get_unchecked_mut(2)
+                .write(InstructionAccount::new(
+                    authority.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+        }
+
+        // Fill signer accounts
+        for (instruction_account, signer) in instruction_accounts[3..]
+            .iter_mut()
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
 
         // Instruction data
         // -  [0]: instruction discriminator (1 byte, u8)
@@ -52,17 +98,41 @@ impl Burn<'_, '_> {
         // Set discriminator as u8 at offset [0]
         write_bytes(&mut instruction_data, &[8]);
         // Set amount as u64 at offset [1..9]
-        write_bytes(&mut instruction_data[1..], &self.amount.to_le_bytes());
+        write_bytes(&mut instruction_data[1..], &amount.to_le_bytes());
+
+        let num_accounts = 3 + multisig_accounts.len();
 
         let instruction = InstructionView {
-            program_id: self.token_program,
-            accounts: &instruction_accounts,
-            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 9) },
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 9) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
         };
 
-        invoke_signed(
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 2 are always present
+            account_views.get_unchecked_mut(0).write(account);
+            account_views.get_unchecked_mut(1).write(mint);
+            account_views.get_unchecked_mut(2).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_view, signer) in account_views[3..].iter_mut().zip(multisig_accounts.iter()) {
+            account_view.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
             &instruction,
-            &[self.account, self.mint, self.authority],
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
             signers,
         )
     }
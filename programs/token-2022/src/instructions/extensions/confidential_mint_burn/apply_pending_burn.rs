@@ -0,0 +1,121 @@
+use {
+    crate::instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Apply the pending burn amount of a mint to its confidential supply.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The token mint.
+/// 1. `[signer]` The confidential transfer mint authority.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The token mint.
+/// 1. `[]` The multisig confidential transfer mint authority.
+/// 2. `..2+M` `[signer]` M signer accounts.
+pub struct ApplyPendingBurn<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The confidential transfer mint authority (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl ApplyPendingBurn<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 5;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            authority,
+            signers: multisig_accounts,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 2 + MAX_MULTISIG_SIGNERS];
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to 2 + MAX_MULTISIG_SIGNERS
+            // - Index 0 is always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(mint.address()));
+            account_views.get_unchecked_mut(0).write(mint);
+        }
+
+        let mut num_accounts = 1;
+
+        instruction_accounts[num_accounts].write(InstructionAccount::new(
+            authority.address(),
+            false,
+            multisig_accounts.is_empty(),
+        ));
+        account_views[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[num_accounts..]
+            .iter_mut()
+            .zip(account_views[num_accounts..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        num_accounts += multisig_accounts.len();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        let data = &[
+            ExtensionDiscriminator::ConfidentialMintBurn as u8,
+            Self::DISCRIMINATOR,
+        ];
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data,
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,68 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{
+    instructions::extensions::ExtensionDiscriminator,
+    state::{PodAeCiphertext, PodElGamalPubkey},
+    write_bytes, UNINIT_BYTE,
+};
+
+/// Initialize confidential mint and burn for a new mint.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializeMint<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The ElGamal public key used to encrypt the confidential supply.
+    pub supply_elgamal_pubkey: &'b PodElGamalPubkey,
+    /// The initial decryptable supply (always 0).
+    pub decryptable_supply: &'b PodAeCiphertext,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeMint<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..34]: supply_elgamal_pubkey (32 bytes, PodElGamalPubkey)
+        // -  [34..70]: decryptable_supply (36 bytes, PodAeCiphertext)
+        let mut instruction_data = [UNINIT_BYTE; 70];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialMintBurn as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set supply_elgamal_pubkey as PodElGamalPubkey at offset [2..34]
+        write_bytes(&mut instruction_data[2..34], self.supply_elgamal_pubkey);
+        // Set decryptable_supply as PodAeCiphertext at offset [34..70]
+        write_bytes(&mut instruction_data[34..], self.decryptable_supply);
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 70) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
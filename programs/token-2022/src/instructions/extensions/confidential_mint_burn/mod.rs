@@ -0,0 +1,9 @@
+pub mod apply_pending_burn;
+pub mod burn;
+pub mod initialize_mint;
+pub mod mint;
+pub mod rotate_supply_elgamal_pubkey;
+
+pub use {
+    apply_pending_burn::*, burn::*, initialize_mint::*, mint::*, rotate_supply_elgamal_pubkey::*,
+};
@@ -0,0 +1,163 @@
+use {
+    crate::{
+        instructions::{
+            extensions::{
+                confidential_transfer::{write_proof_accounts, ProofLocation},
+                ExtensionDiscriminator,
+            },
+            MAX_MULTISIG_SIGNERS,
+        },
+        state::PodElGamalPubkey,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Rotate the ElGamal public key encrypting the confidential supply of a mint.
+///
+/// The `CiphertextCiphertextEquality` proof certifies that the supply is
+/// correctly re-encrypted under the new ElGamal public key.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The token mint.
+/// 1. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 2. `[]` (Optional) `CiphertextCiphertextEquality` proof context state account.
+/// 3. `[signer]` The confidential transfer mint authority.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The token mint.
+/// 1. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 2. `[]` (Optional) `CiphertextCiphertextEquality` proof context state account.
+/// 3. `[]` The multisig confidential transfer mint authority.
+/// 4. `..4+M` `[signer]` M signer accounts.
+pub struct RotateSupplyElGamalPubkey<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Location of the `CiphertextCiphertextEquality` proof.
+    pub proof: ProofLocation<'a>,
+    /// The confidential transfer mint authority (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// The new ElGamal public key for supply encryption.
+    pub new_supply_elgamal_pubkey: &'b PodElGamalPubkey,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl RotateSupplyElGamalPubkey<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            ref proof,
+            authority,
+            signers: multisig_accounts,
+            new_supply_elgamal_pubkey,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 3 + MAX_MULTISIG_SIGNERS];
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 3 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to 3 + MAX_MULTISIG_SIGNERS
+            // - Index 0 is always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(mint.address()));
+            account_views.get_unchecked_mut(0).write(mint);
+        }
+
+        // Fill proof accounts
+        let mut num_accounts = 1 + write_proof_accounts(
+            &[proof],
+            &mut instruction_accounts[1..],
+            &mut account_views[1..],
+        )?;
+
+        instruction_accounts[num_accounts].write(InstructionAccount::new(
+            authority.address(),
+            false,
+            multisig_accounts.is_empty(),
+        ));
+        account_views[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[num_accounts..]
+            .iter_mut()
+            .zip(account_views[num_accounts..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        num_accounts += multisig_accounts.len();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..34]: new_supply_elgamal_pubkey (32 bytes, PodElGamalPubkey)
+        // -  [34]: proof_instruction_offset (1 byte, i8)
+        let mut instruction_data = [UNINIT_BYTE; 35];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialMintBurn as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set new_supply_elgamal_pubkey as PodElGamalPubkey at offset [2..34]
+        write_bytes(&mut instruction_data[2..34], new_supply_elgamal_pubkey);
+        // Set proof_instruction_offset as i8 at offset [34]
+        write_bytes(&mut instruction_data[34..], &proof.offset().to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 35) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<{ 3 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,149 @@
+use {
+    crate::{
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        state::PodAeCiphertext,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Apply the pending balance of a token account to its available balance.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The token account.
+/// 1. `[signer]` The single account owner.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The token account.
+/// 1. `[]` The multisig account owner.
+/// 2. `..2+M` `[signer]` M signer accounts.
+pub struct ApplyPendingBalance<'a, 'b, 'c> {
+    /// The token account.
+    pub token_account: &'a AccountView,
+    /// The account owner (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// The expected number of pending balance credits since the last successful `ApplyPendingBalance` instruction.
+    pub expected_pending_balance_credit_counter: u64,
+    /// The new decryptable balance if the pending balance is applied successfully.
+    pub new_decryptable_available_balance: &'b PodAeCiphertext,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl ApplyPendingBalance<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 8;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            token_account,
+            authority,
+            signers: multisig_accounts,
+            expected_pending_balance_credit_counter,
+            new_decryptable_available_balance,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 2 + MAX_MULTISIG_SIGNERS];
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to 2 + MAX_MULTISIG_SIGNERS
+            // - Index 0 is always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(token_account.address()));
+            account_views.get_unchecked_mut(0).write(token_account);
+        }
+
+        let mut num_accounts = 1;
+
+        instruction_accounts[num_accounts].write(InstructionAccount::new(
+            authority.address(),
+            false,
+            multisig_accounts.is_empty(),
+        ));
+        account_views[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[num_accounts..]
+            .iter_mut()
+            .zip(account_views[num_accounts..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        num_accounts += multisig_accounts.len();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..10]: expected_pending_balance_credit_counter (8 bytes, u64)
+        // -  [10..46]: new_decryptable_available_balance (36 bytes, PodAeCiphertext)
+        let mut instruction_data = [UNINIT_BYTE; 46];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialTransfer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set expected_pending_balance_credit_counter as u64 at offset [2..10]
+        write_bytes(
+            &mut instruction_data[2..10],
+            &expected_pending_balance_credit_counter.to_le_bytes(),
+        );
+        // Set new_decryptable_available_balance as PodAeCiphertext at offset [10..46]
+        write_bytes(
+            &mut instruction_data[10..],
+            new_decryptable_available_balance,
+        );
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 46) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use crate::instructions::extensions::ExtensionDiscriminator;
+
+/// Approve a token account for confidential transfers. Only required when
+/// the mint does not auto-approve new accounts.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The token account to approve.
+///   1. `[]` The corresponding token mint.
+///   2. `[SIGNER]` The confidential transfer mint authority.
+pub struct ApproveAccount<'a, 'b> {
+    /// The token account to approve.
+    pub token_account: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The confidential transfer mint authority.
+    pub authority: &'a AccountView,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl ApproveAccount<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 3;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.token_account.address()),
+            InstructionAccount::readonly(self.mint.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: &[
+                ExtensionDiscriminator::ConfidentialTransfer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.token_account, self.mint, self.authority],
+            signers,
+        )
+    }
+}
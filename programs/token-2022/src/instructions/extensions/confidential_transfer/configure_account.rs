@@ -0,0 +1,176 @@
+use {
+    super::{write_proof_accounts, ProofLocation},
+    crate::{
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        state::PodAeCiphertext,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Configure a token account for confidential transfers.
+///
+/// The `VerifyPubkeyValidity` proof certifies the ElGamal public key of the
+/// account.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The token account.
+/// 1. `[]` The corresponding token mint.
+/// 2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 3. `[]` (Optional) `VerifyPubkeyValidity` proof context state account.
+/// 4. `[signer]` The single account owner.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The token account.
+/// 1. `[]` The corresponding token mint.
+/// 2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 3. `[]` (Optional) `VerifyPubkeyValidity` proof context state account.
+/// 4. `[]` The multisig account owner.
+/// 5. `..5+M` `[signer]` M signer accounts.
+pub struct ConfigureAccount<'a, 'b, 'c> {
+    /// The token account.
+    pub token_account: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Location of the `VerifyPubkeyValidity` proof.
+    pub proof: ProofLocation<'a>,
+    /// The account owner (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// The decryptable balance (always 0) once the configuration is applied.
+    pub decryptable_zero_balance: &'b PodAeCiphertext,
+    /// The maximum number of `Deposit` and `Transfer` instructions that can credit the pending balance before `ApplyPendingBalance` is executed.
+    pub maximum_pending_balance_credit_counter: u64,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl ConfigureAccount<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 2;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            token_account,
+            mint,
+            ref proof,
+            authority,
+            signers: multisig_accounts,
+            decryptable_zero_balance,
+            maximum_pending_balance_credit_counter,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 4 + MAX_MULTISIG_SIGNERS];
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 and 1 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(token_account.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::readonly(mint.address()));
+            account_views.get_unchecked_mut(0).write(token_account);
+            account_views.get_unchecked_mut(1).write(mint);
+        }
+
+        // Fill proof accounts
+        let mut num_accounts = 2 + write_proof_accounts(
+            &[proof],
+            &mut instruction_accounts[2..],
+            &mut account_views[2..],
+        )?;
+
+        instruction_accounts[num_accounts].write(InstructionAccount::new(
+            authority.address(),
+            false,
+            multisig_accounts.is_empty(),
+        ));
+        account_views[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[num_accounts..]
+            .iter_mut()
+            .zip(account_views[num_accounts..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        num_accounts += multisig_accounts.len();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..38]: decryptable_zero_balance (36 bytes, PodAeCiphertext)
+        // -  [38..46]: maximum_pending_balance_credit_counter (8 bytes, u64)
+        // -  [46]: proof_instruction_offset (1 byte, i8)
+        let mut instruction_data = [UNINIT_BYTE; 47];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialTransfer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set decryptable_zero_balance as PodAeCiphertext at offset [2..38]
+        write_bytes(&mut instruction_data[2..38], decryptable_zero_balance);
+        // Set maximum_pending_balance_credit_counter as u64 at offset [38..46]
+        write_bytes(
+            &mut instruction_data[38..46],
+            &maximum_pending_balance_credit_counter.to_le_bytes(),
+        );
+        // Set proof_instruction_offset as i8 at offset [46]
+        write_bytes(&mut instruction_data[46..], &proof.offset().to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 47) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
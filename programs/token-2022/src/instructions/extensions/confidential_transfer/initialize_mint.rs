@@ -0,0 +1,84 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{
+    instructions::extensions::ExtensionDiscriminator, state::PodElGamalPubkey, write_bytes,
+    UNINIT_BYTE,
+};
+
+/// Initialize confidential transfers for a mint.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializeMint<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The public key for the account that can update the confidential
+    /// transfer configuration and approve accounts.
+    pub authority: Option<&'b Address>,
+    /// Whether new token accounts are approved to make confidential transfers
+    /// on configuration.
+    pub auto_approve_new_accounts: bool,
+    /// The ElGamal public key of the auditor of confidential transfers.
+    pub auditor_elgamal_pubkey: Option<&'b PodElGamalPubkey>,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeMint<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..34]: authority (32 bytes, Address; zeroed if `None`)
+        // -  [34]: auto_approve_new_accounts (1 byte, bool)
+        // -  [35..67]: auditor_elgamal_pubkey (32 bytes, PodElGamalPubkey; zeroed if `None`)
+        let mut instruction_data = [UNINIT_BYTE; 67];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialTransfer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set authority as Address at offset [2..34]
+        if let Some(authority) = self.authority {
+            write_bytes(&mut instruction_data[2..34], authority.as_array());
+        } else {
+            write_bytes(&mut instruction_data[2..34], &[0; 32]);
+        }
+        // Set auto_approve_new_accounts as bool at offset [34]
+        write_bytes(
+            &mut instruction_data[34..35],
+            &[self.auto_approve_new_accounts as u8],
+        );
+        // Set auditor_elgamal_pubkey as PodElGamalPubkey at offset [35..67]
+        write_bytes(
+            &mut instruction_data[35..],
+            self.auditor_elgamal_pubkey.unwrap_or(&[0; 32]),
+        );
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 67) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
@@ -0,0 +1,99 @@
+pub mod apply_pending_balance;
+pub mod approve_account;
+pub mod configure_account;
+pub mod deposit;
+pub mod disable_confidential_credits;
+pub mod disable_non_confidential_credits;
+pub mod empty_account;
+pub mod enable_confidential_credits;
+pub mod enable_non_confidential_credits;
+pub mod initialize_mint;
+pub mod transfer;
+pub mod update_mint;
+pub mod withdraw;
+
+pub use {
+    apply_pending_balance::*, approve_account::*, configure_account::*, deposit::*,
+    disable_confidential_credits::*, disable_non_confidential_credits::*, empty_account::*,
+    enable_confidential_credits::*, enable_non_confidential_credits::*, initialize_mint::*,
+    transfer::*, update_mint::*, withdraw::*,
+};
+
+use {
+    core::mem::MaybeUninit, solana_account_view::AccountView,
+    solana_instruction_view::InstructionAccount, solana_program_error::ProgramError,
+};
+
+/// Location of a zero-knowledge proof required by an instruction.
+pub enum ProofLocation<'a> {
+    /// The proof is in the instruction at the given offset, relative to the
+    /// current instruction, of the same transaction.
+    InstructionOffset {
+        /// Relative offset of the proof instruction (must not be `0`).
+        offset: i8,
+        /// Instructions sysvar account.
+        instructions_sysvar: &'a AccountView,
+    },
+    /// The proof is pre-verified into a context state account.
+    ContextStateAccount(&'a AccountView),
+}
+
+impl ProofLocation<'_> {
+    /// Instruction offset of the proof, `0` if the proof is in a context state
+    /// account.
+    #[inline(always)]
+    pub fn offset(&self) -> i8 {
+        match self {
+            Self::InstructionOffset { offset, .. } => *offset,
+            Self::ContextStateAccount(_) => 0,
+        }
+    }
+}
+
+/// Write the instructions sysvar, if any of the proofs is in an instruction of
+/// the transaction, followed by the context state accounts of the other proofs.
+///
+/// Returns the number of accounts written.
+#[inline(always)]
+pub(crate) fn write_proof_accounts<'a>(
+    proofs: &[&ProofLocation<'a>],
+    instruction_accounts: &mut [MaybeUninit<InstructionAccount<'a>>],
+    account_views: &mut [MaybeUninit<&'a AccountView>],
+) -> Result<usize, ProgramError> {
+    let mut num_accounts = 0;
+    let mut write_account = |account: &'a AccountView| {
+        instruction_accounts[num_accounts].write(InstructionAccount::readonly(account.address()));
+        account_views[num_accounts].write(account);
+        num_accounts += 1;
+    };
+
+    // The instructions sysvar is only included once.
+    let mut sysvar = None;
+
+    for proof in proofs {
+        match proof {
+            ProofLocation::InstructionOffset { offset: 0, .. } => {
+                return Err(ProgramError::InvalidArgument);
+            }
+            ProofLocation::InstructionOffset {
+                instructions_sysvar,
+                ..
+            } => {
+                sysvar.get_or_insert(*instructions_sysvar);
+            }
+            ProofLocation::ContextStateAccount(_) => (),
+        }
+    }
+
+    if let Some(instructions_sysvar) = sysvar {
+        write_account(instructions_sysvar);
+    }
+
+    for proof in proofs {
+        if let ProofLocation::ContextStateAccount(context_state) = proof {
+            write_account(context_state);
+        }
+    }
+
+    Ok(num_accounts)
+}
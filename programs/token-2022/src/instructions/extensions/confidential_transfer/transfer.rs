@@ -0,0 +1,223 @@
+use {
+    super::{write_proof_accounts, ProofLocation},
+    crate::{
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        state::{PodAeCiphertext, PodElGamalCiphertext},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Transfer tokens confidentially from the available balance of a token
+/// account to the pending balance of another.
+///
+/// The `CiphertextCommitmentEquality`, `BatchedGroupedCiphertext3HandlesValidity`
+/// and `BatchedRangeProofU128` proofs certify the transfer.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The source token account.
+/// 1. `[]` The corresponding token mint.
+/// 2. `[writable]` The destination token account.
+/// 3. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 4. `[]` (Optional) `CiphertextCommitmentEquality` proof context state account.
+/// 5. `[]` (Optional) `BatchedGroupedCiphertext3HandlesValidity` proof context state account.
+/// 6. `[]` (Optional) `BatchedRangeProofU128` proof context state account.
+/// 7. `[signer]` The single source account owner.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The source token account.
+/// 1. `[]` The corresponding token mint.
+/// 2. `[writable]` The destination token account.
+/// 3. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 4. `[]` (Optional) `CiphertextCommitmentEquality` proof context state account.
+/// 5. `[]` (Optional) `BatchedGroupedCiphertext3HandlesValidity` proof context state account.
+/// 6. `[]` (Optional) `BatchedRangeProofU128` proof context state account.
+/// 7. `[]` The multisig source account owner.
+/// 8. `..8+M` `[signer]` M signer accounts.
+pub struct Transfer<'a, 'b, 'c> {
+    /// Source Account.
+    pub source: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Destination Account.
+    pub destination: &'a AccountView,
+    /// Location of the `CiphertextCommitmentEquality` proof.
+    pub equality_proof: ProofLocation<'a>,
+    /// Location of the `BatchedGroupedCiphertext3HandlesValidity` proof.
+    pub ciphertext_validity_proof: ProofLocation<'a>,
+    /// Location of the `BatchedRangeProofU128` proof.
+    pub range_proof: ProofLocation<'a>,
+    /// The source account owner (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// The new source decryptable balance if the transfer succeeds.
+    pub new_source_decryptable_available_balance: &'b PodAeCiphertext,
+    /// The transfer amount low bits encrypted under the auditor ElGamal public key.
+    pub transfer_amount_auditor_ciphertext_lo: &'b PodElGamalCiphertext,
+    /// The transfer amount high bits encrypted under the auditor ElGamal public key.
+    pub transfer_amount_auditor_ciphertext_hi: &'b PodElGamalCiphertext,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl Transfer<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 7;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            source,
+            mint,
+            destination,
+            ref equality_proof,
+            ref ciphertext_validity_proof,
+            ref range_proof,
+            authority,
+            signers: multisig_accounts,
+            new_source_decryptable_available_balance,
+            transfer_amount_auditor_ciphertext_lo,
+            transfer_amount_auditor_ciphertext_hi,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 7 + MAX_MULTISIG_SIGNERS];
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 7 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to 7 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 2 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(source.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::readonly(mint.address()));
+            instruction_accounts
+                .get_unchecked_mut(2)
+                .write(InstructionAccount::writable(destination.address()));
+            account_views.get_unchecked_mut(0).write(source);
+            account_views.get_unchecked_mut(1).write(mint);
+            account_views.get_unchecked_mut(2).write(destination);
+        }
+
+        // Fill proof accounts
+        let mut num_accounts = 3 + write_proof_accounts(
+            &[equality_proof, ciphertext_validity_proof, range_proof],
+            &mut instruction_accounts[3..],
+            &mut account_views[3..],
+        )?;
+
+        instruction_accounts[num_accounts].write(InstructionAccount::new(
+            authority.address(),
+            false,
+            multisig_accounts.is_empty(),
+        ));
+        account_views[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[num_accounts..]
+            .iter_mut()
+            .zip(account_views[num_accounts..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        num_accounts += multisig_accounts.len();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..38]: new_source_decryptable_available_balance (36 bytes, PodAeCiphertext)
+        // -  [38..102]: transfer_amount_auditor_ciphertext_lo (64 bytes, PodElGamalCiphertext)
+        // -  [102..166]: transfer_amount_auditor_ciphertext_hi (64 bytes, PodElGamalCiphertext)
+        // -  [166]: equality_proof_instruction_offset (1 byte, i8)
+        // -  [167]: ciphertext_validity_proof_instruction_offset (1 byte, i8)
+        // -  [168]: range_proof_instruction_offset (1 byte, i8)
+        let mut instruction_data = [UNINIT_BYTE; 169];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialTransfer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set new_source_decryptable_available_balance as PodAeCiphertext at offset [2..38]
+        write_bytes(
+            &mut instruction_data[2..38],
+            new_source_decryptable_available_balance,
+        );
+        // Set transfer_amount_auditor_ciphertext_lo as PodElGamalCiphertext at offset [38..102]
+        write_bytes(
+            &mut instruction_data[38..102],
+            transfer_amount_auditor_ciphertext_lo,
+        );
+        // Set transfer_amount_auditor_ciphertext_hi as PodElGamalCiphertext at offset [102..166]
+        write_bytes(
+            &mut instruction_data[102..166],
+            transfer_amount_auditor_ciphertext_hi,
+        );
+        // Set equality_proof_instruction_offset as i8 at offset [166]
+        write_bytes(
+            &mut instruction_data[166..167],
+            &equality_proof.offset().to_le_bytes(),
+        );
+        // Set ciphertext_validity_proof_instruction_offset as i8 at offset [167]
+        write_bytes(
+            &mut instruction_data[167..168],
+            &ciphertext_validity_proof.offset().to_le_bytes(),
+        );
+        // Set range_proof_instruction_offset as i8 at offset [168]
+        write_bytes(
+            &mut instruction_data[168..],
+            &range_proof.offset().to_le_bytes(),
+        );
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 169) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<{ 7 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,85 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use crate::{
+    instructions::extensions::ExtensionDiscriminator, state::PodElGamalPubkey, write_bytes,
+    UNINIT_BYTE,
+};
+
+/// Update the confidential transfer configuration of a mint.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint.
+///   1. `[SIGNER]` The confidential transfer mint authority.
+pub struct UpdateMint<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The confidential transfer mint authority.
+    pub authority: &'a AccountView,
+    /// Whether new token accounts are approved to make confidential transfers
+    /// on configuration.
+    pub auto_approve_new_accounts: bool,
+    /// The new ElGamal public key of the auditor of confidential transfers.
+    pub auditor_elgamal_pubkey: Option<&'b PodElGamalPubkey>,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl UpdateMint<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.mint.address()),
+            InstructionAccount::readonly_signer(self.authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2]: auto_approve_new_accounts (1 byte, bool)
+        // -  [3..35]: auditor_elgamal_pubkey (32 bytes, PodElGamalPubkey; zeroed if `None`)
+        let mut instruction_data = [UNINIT_BYTE; 35];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialTransfer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set auto_approve_new_accounts as bool at offset [2]
+        write_bytes(
+            &mut instruction_data[2..3],
+            &[self.auto_approve_new_accounts as u8],
+        );
+        // Set auditor_elgamal_pubkey as PodElGamalPubkey at offset [3..35]
+        write_bytes(
+            &mut instruction_data[3..],
+            self.auditor_elgamal_pubkey.unwrap_or(&[0; 32]),
+        );
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 35) },
+        };
+
+        invoke_signed(&instruction, &[self.mint, self.authority], signers)
+    }
+}
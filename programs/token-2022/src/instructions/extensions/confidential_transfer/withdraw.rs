@@ -0,0 +1,197 @@
+use {
+    super::{write_proof_accounts, ProofLocation},
+    crate::{
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        state::PodAeCiphertext,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Withdraw tokens from the available balance of a token account into its
+/// non-confidential balance.
+///
+/// The `CiphertextCommitmentEquality` and `BatchedRangeProofU64` proofs
+/// certify the new available balance.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The token account.
+/// 1. `[]` The corresponding token mint.
+/// 2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 3. `[]` (Optional) `CiphertextCommitmentEquality` proof context state account.
+/// 4. `[]` (Optional) `BatchedRangeProofU64` proof context state account.
+/// 5. `[signer]` The single account owner.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The token account.
+/// 1. `[]` The corresponding token mint.
+/// 2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 3. `[]` (Optional) `CiphertextCommitmentEquality` proof context state account.
+/// 4. `[]` (Optional) `BatchedRangeProofU64` proof context state account.
+/// 5. `[]` The multisig account owner.
+/// 6. `..6+M` `[signer]` M signer accounts.
+pub struct Withdraw<'a, 'b, 'c> {
+    /// The token account.
+    pub token_account: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Location of the `CiphertextCommitmentEquality` proof.
+    pub equality_proof: ProofLocation<'a>,
+    /// Location of the `BatchedRangeProofU64` proof.
+    pub range_proof: ProofLocation<'a>,
+    /// The account owner (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// Amount of tokens to withdraw.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// The new decryptable balance if the withdrawal succeeds.
+    pub new_decryptable_available_balance: &'b PodAeCiphertext,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl Withdraw<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 6;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            token_account,
+            mint,
+            ref equality_proof,
+            ref range_proof,
+            authority,
+            signers: multisig_accounts,
+            amount,
+            decimals,
+            new_decryptable_available_balance,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 5 + MAX_MULTISIG_SIGNERS];
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 5 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to 5 + MAX_MULTISIG_SIGNERS
+            // - Index 0 and 1 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(token_account.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::readonly(mint.address()));
+            account_views.get_unchecked_mut(0).write(token_account);
+            account_views.get_unchecked_mut(1).write(mint);
+        }
+
+        // Fill proof accounts
+        let mut num_accounts = 2 + write_proof_accounts(
+            &[equality_proof, range_proof],
+            &mut instruction_accounts[2..],
+            &mut account_views[2..],
+        )?;
+
+        instruction_accounts[num_accounts].write(InstructionAccount::new(
+            authority.address(),
+            false,
+            multisig_accounts.is_empty(),
+        ));
+        account_views[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[num_accounts..]
+            .iter_mut()
+            .zip(account_views[num_accounts..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        num_accounts += multisig_accounts.len();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..10]: amount (8 bytes, u64)
+        // -  [10]: decimals (1 byte, u8)
+        // -  [11..47]: new_decryptable_available_balance (36 bytes, PodAeCiphertext)
+        // -  [47]: equality_proof_instruction_offset (1 byte, i8)
+        // -  [48]: range_proof_instruction_offset (1 byte, i8)
+        let mut instruction_data = [UNINIT_BYTE; 49];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialTransfer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set amount as u64 at offset [2..10]
+        write_bytes(&mut instruction_data[2..10], &amount.to_le_bytes());
+        // Set decimals as u8 at offset [10]
+        write_bytes(&mut instruction_data[10..11], &[decimals]);
+        // Set new_decryptable_available_balance as PodAeCiphertext at offset [11..47]
+        write_bytes(
+            &mut instruction_data[11..47],
+            new_decryptable_available_balance,
+        );
+        // Set equality_proof_instruction_offset as i8 at offset [47]
+        write_bytes(
+            &mut instruction_data[47..48],
+            &equality_proof.offset().to_le_bytes(),
+        );
+        // Set range_proof_instruction_offset as i8 at offset [48]
+        write_bytes(
+            &mut instruction_data[48..],
+            &range_proof.offset().to_le_bytes(),
+        );
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 49) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<{ 5 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,12 @@
+pub mod disable_harvest_to_mint;
+pub mod enable_harvest_to_mint;
+pub mod harvest_withheld_tokens_to_mint;
+pub mod initialize_confidential_transfer_fee_config;
+pub mod withdraw_withheld_tokens_from_accounts;
+pub mod withdraw_withheld_tokens_from_mint;
+
+pub use {
+    disable_harvest_to_mint::*, enable_harvest_to_mint::*, harvest_withheld_tokens_to_mint::*,
+    initialize_confidential_transfer_fee_config::*, withdraw_withheld_tokens_from_accounts::*,
+    withdraw_withheld_tokens_from_mint::*,
+};
@@ -0,0 +1,175 @@
+use {
+    crate::{
+        instructions::{
+            extensions::{
+                confidential_transfer::{write_proof_accounts, ProofLocation},
+                ExtensionDiscriminator,
+            },
+            MAX_MULTISIG_SIGNERS,
+        },
+        state::PodAeCiphertext,
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Transfer all withheld confidential tokens in the mint to an account.
+///
+/// The `CiphertextCiphertextEquality` proof certifies that the withheld amount
+/// is correctly re-encrypted under the destination ElGamal public key.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The token mint.
+/// 1. `[writable]` The fee receiver account.
+/// 2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 3. `[]` (Optional) `CiphertextCiphertextEquality` proof context state account.
+/// 4. `[signer]` The mint's `withdraw_withheld_authority`.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The token mint.
+/// 1. `[writable]` The fee receiver account.
+/// 2. `[]` (Optional) Instructions sysvar if at least one of the proofs is
+///    included in the same transaction.
+/// 3. `[]` (Optional) `CiphertextCiphertextEquality` proof context state account.
+/// 4. `[]` The mint's multisig `withdraw_withheld_authority`.
+/// 5. `..5+M` `[signer]` M signer accounts.
+pub struct WithdrawWithheldTokensFromMint<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The fee receiver account.
+    pub destination: &'a AccountView,
+    /// Location of the `CiphertextCiphertextEquality` proof.
+    pub proof: ProofLocation<'a>,
+    /// The withdraw withheld authority (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// The new decryptable balance of the destination account.
+    pub new_decryptable_available_balance: &'b PodAeCiphertext,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl WithdrawWithheldTokensFromMint<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            destination,
+            ref proof,
+            authority,
+            signers: multisig_accounts,
+            new_decryptable_available_balance,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 4 + MAX_MULTISIG_SIGNERS];
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 and 1 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(mint.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::writable(destination.address()));
+            account_views.get_unchecked_mut(0).write(mint);
+            account_views.get_unchecked_mut(1).write(destination);
+        }
+
+        // Fill proof accounts
+        let mut num_accounts = 2 + write_proof_accounts(
+            &[proof],
+            &mut instruction_accounts[2..],
+            &mut account_views[2..],
+        )?;
+
+        instruction_accounts[num_accounts].write(InstructionAccount::new(
+            authority.address(),
+            false,
+            multisig_accounts.is_empty(),
+        ));
+        account_views[num_accounts].write(authority);
+        num_accounts += 1;
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[num_accounts..]
+            .iter_mut()
+            .zip(account_views[num_accounts..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        num_accounts += multisig_accounts.len();
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2]: proof_instruction_offset (1 byte, i8)
+        // -  [3..39]: new_decryptable_available_balance (36 bytes, PodAeCiphertext)
+        let mut instruction_data = [UNINIT_BYTE; 39];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::ConfidentialTransferFee as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set proof_instruction_offset as i8 at offset [2]
+        write_bytes(&mut instruction_data[2..3], &proof.offset().to_le_bytes());
+        // Set new_decryptable_available_balance as PodAeCiphertext at offset [3..39]
+        write_bytes(
+            &mut instruction_data[3..],
+            new_decryptable_available_balance,
+        );
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 39) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
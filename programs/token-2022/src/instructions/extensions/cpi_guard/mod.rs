@@ -0,0 +1,4 @@
+pub mod disable;
+pub mod enable;
+
+pub use {disable::*, enable::*};
@@ -0,0 +1,50 @@
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{instructions::extensions::ExtensionDiscriminator, state::AccountState};
+
+/// Initialize a new mint with the default state for new accounts.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializeDefaultAccountState<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Default state for new accounts.
+    pub state: AccountState,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeDefaultAccountState<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2]: state (1 byte, u8)
+        let data = &[
+            ExtensionDiscriminator::DefaultAccountState as u8,
+            Self::DISCRIMINATOR,
+            self.state.into(),
+        ];
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data,
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
@@ -0,0 +1,72 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE};
+
+/// Initialize a new mint with a group pointer.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct Initialize<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The public key for the account that can update the group address.
+    pub authority: Option<&'b Address>,
+    /// The account address that holds the group configurations.
+    pub group_address: Option<&'b Address>,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl Initialize<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..34]: authority (32 bytes, Address; zeroed if `None`)
+        // -  [34..66]: group_address (32 bytes, Address; zeroed if `None`)
+        let mut instruction_data = [UNINIT_BYTE; 66];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::GroupPointer as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set authority as Address at offset [2..34]
+        if let Some(authority) = self.authority {
+            write_bytes(&mut instruction_data[2..34], authority.as_array());
+        } else {
+            write_bytes(&mut instruction_data[2..34], &[0; 32]);
+        }
+        // Set group_address as Address at offset [34..66]
+        if let Some(group_address) = self.group_address {
+            write_bytes(&mut instruction_data[34..], group_address.as_array());
+        } else {
+            write_bytes(&mut instruction_data[34..], &[0; 32]);
+        }
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 66) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
@@ -0,0 +1,68 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE};
+
+/// Initialize a new mint with the interest-bearing extension.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializeInterestBearingMint<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The public key for the account that can update the rate.
+    pub rate_authority: Option<&'b Address>,
+    /// The initial interest rate, in basis points.
+    pub rate: i16,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeInterestBearingMint<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..34]: rate_authority (32 bytes, Address; zeroed if `None`)
+        // -  [34..36]: rate (2 bytes, i16)
+        let mut instruction_data = [UNINIT_BYTE; 36];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::InterestBearingMint as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set rate_authority as Address at offset [2..34]
+        if let Some(rate_authority) = self.rate_authority {
+            write_bytes(&mut instruction_data[2..34], rate_authority.as_array());
+        } else {
+            write_bytes(&mut instruction_data[2..34], &[0; 32]);
+        }
+        // Set rate as i16 at offset [34..36]
+        write_bytes(&mut instruction_data[34..], &self.rate.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 36) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
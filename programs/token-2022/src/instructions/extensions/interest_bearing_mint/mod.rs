@@ -0,0 +1,5 @@
+pub mod initialize;
+pub mod update_rate;
+
+pub use initialize::*;
+pub use update_rate::*;
@@ -1,4 +1,5 @@
 pub mod disable;
 pub mod enable;
+pub mod transfer_checked_with_memo;
 
-pub use {disable::*, enable::*};
+pub use {disable::*, enable::*, transfer_checked_with_memo::*};
@@ -0,0 +1,90 @@
+use pinocchio_memo::instructions::Memo;
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::cpi::Signer;
+use solana_program_error::{ProgramError, ProgramResult};
+
+use crate::{
+    instructions::TransferChecked,
+    state::{Extension, MemoTransfer},
+};
+
+/// Transfer tokens from one token account to another, preceded by a memo when
+/// the destination account requires incoming transfer memos.
+///
+/// The memo is emitted through the Memo program right before `TransferChecked`,
+/// as Token-2022 expects it to be the previous instruction at the same level.
+///
+/// ### Accounts:
+///   * Single authority
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[]` The source account's multisignature owner/delegate.
+///   4. ..4+M `[SIGNER]` M signer accounts.
+pub struct TransferCheckedWithMemo<'a, 'b, 'c> {
+    /// Sender account.
+    pub from: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Recipient account.
+    pub to: &'a AccountView,
+    /// Authority account (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token.
+    pub decimals: u8,
+    /// Memo to emit if the recipient requires incoming transfer memos.
+    pub memo: &'c str,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl TransferCheckedWithMemo<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.requires_memo()? {
+            Memo {
+                signers: &[],
+                memo: self.memo,
+            }
+            .invoke()?;
+        }
+
+        TransferChecked {
+            from: self.from,
+            mint: self.mint,
+            to: self.to,
+            authority: self.authority,
+            signers: self.signers,
+            amount: self.amount,
+            decimals: self.decimals,
+            token_program: self.token_program,
+        }
+        .invoke_signed(signers)
+    }
+
+    /// Indicates whether the recipient account requires incoming transfer memos.
+    ///
+    /// Accounts without the `MemoTransfer` extension do not require memos. An
+    /// error is returned if the recipient is not a valid Token-2022 account.
+    #[inline(always)]
+    fn requires_memo(&self) -> Result<bool, ProgramError> {
+        Ok(MemoTransfer::from_account_view(self.to)?
+            .is_some_and(|memo_transfer| memo_transfer.require_incoming_transfer_memos()))
+    }
+}
@@ -1,5 +1,19 @@
+pub mod confidential_mint_burn;
+pub mod confidential_transfer;
+pub mod confidential_transfer_fee;
+pub mod cpi_guard;
+pub mod default_account_state;
+pub mod group_member_pointer;
+pub mod group_pointer;
+pub mod interest_bearing_mint;
 pub mod memo_transfer;
+pub mod metadata_pointer;
 pub mod pausable;
+pub mod scaled_ui_amount;
+pub mod token_group;
+pub mod token_metadata;
+pub mod transfer_fee;
+pub mod transfer_hook;
 
 #[repr(u8)]
 #[non_exhaustive]
@@ -7,4 +21,15 @@ pub enum ExtensionDiscriminator {
     MemoTransfer = 30,
     Pausable = 44,
     CpiGuard = 34,
+    TransferFee = 26,
+    InterestBearingMint = 33,
+    DefaultAccountState = 28,
+    MetadataPointer = 39,
+    GroupPointer = 40,
+    GroupMemberPointer = 41,
+    TransferHook = 36,
+    ScaledUiAmount = 43,
+    ConfidentialTransfer = 27,
+    ConfidentialTransferFee = 37,
+    ConfidentialMintBurn = 42,
 }
@@ -1,134 +1,56 @@
-use {
-    crate::{instructions::extensions::ExtensionDiscriminator, instructions::MAX_MULTISIG_SIGNERS},
-    core::{mem::MaybeUninit, slice},
-    solana_account_view::AccountView,
-    solana_address::Address,
-    solana_instruction_view::{
-        cpi::{invoke_signed_with_bounds, Signer},
-        InstructionAccount, InstructionView,
-    },
-    solana_program_error::{ProgramError, ProgramResult},
-};
+use core::slice::from_raw_parts;
 
-/// Enable the Pausable extension on a token account.
-///
-/// Expected accounts:
-///
-/// **Single authority**
-///
-/// 0. `[writable]` The token account to initialize pausable token.
-/// 1. `[signer]` The owner of the token account.
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE};
+
+/// Initialize a new mint with the Pausable extension.
 ///
-/// **Multisignature authority**
+/// This instruction must be invoked before `InitializeMint`.
 ///
-/// 0. `[writable]` The token account to initialize pausable token.
-/// 1. `[readonly]` The multisig account that owns the token account.
-/// 2. `[signer]` M signer accounts (as required by the multisig).
-pub struct InitializePausable<'a, 'b, 'c> {
-    /// The token account to enable with the Memo-Transfer extension.
-    pub token_account: &'a AccountView,
-    /// The owner of the token account (single or multisig).
-    pub authority: &'a AccountView,
-    /// Signer accounts if the authority is a multisig.
-    pub signers: &'c [&'a AccountView],
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializePausable<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The public key for the account that can pause and resume the mint.
+    pub authority: &'b Address,
     /// Token program (Token-2022).
     pub token_program: &'b Address,
 }
 
-impl InitializePausable<'_, '_, '_> {
+impl InitializePausable<'_, '_> {
     pub const DISCRIMINATOR: u8 = 0;
 
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
-        self.invoke_signed(&[])
-    }
-
-    #[inline(always)]
-    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        let &Self {
-            token_account,
-            authority,
-            signers: multisig_accounts,
-            token_program,
-            ..
-        } = self;
-
-        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
-            return Err(ProgramError::InvalidArgument);
-        }
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..34]: authority (32 bytes, Address)
+        let mut instruction_data = [UNINIT_BYTE; 34];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[ExtensionDiscriminator::Pausable as u8, Self::DISCRIMINATOR],
+        );
+        // Set authority as Address at offset [2..34]
+        write_bytes(&mut instruction_data[2..], self.authority.as_array());
 
-        // creates an array of uninitialized InstructionAccount with 2 + MAX_MULTISIG_SIGNERS
-        // i.e. [token_account + authority](2) + signers(max_multisig_signers)
-        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
-            MaybeUninit::<InstructionAccount>::uninit();
-        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 2 + MAX_MULTISIG_SIGNERS];
-
-        // SAFETY:
-        // - `instruction_accounts` is sized to 2 + MAX_MULTISIG_SIGNERS
-        unsafe {
-            // - Index 0 is always present (TokenAccount)
-            instruction_accounts
-                .get_unchecked_mut(0)
-                .write(InstructionAccount::writable(token_account.address()));
-
-            // - Index 1 is always present (Authority)
-            instruction_accounts
-                .get_unchecked_mut(1)
-                .write(InstructionAccount::new(
-                    authority.address(),
-                    false,
-                    multisig_accounts.is_empty(),
-                ));
-        }
-
-        // add the multisig if they exist for each signer account
-        // creates a tuple of (account, signer) for each multisig i.e from index 2
-        for (instruction_account, signer) in instruction_accounts[2..]
-            .iter_mut()
-            .zip(multisig_accounts.iter())
-        {
-            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
-        }
-
-        // build instruction data for Pausable
-        let data = &[ExtensionDiscriminator::Pausable as u8, Self::DISCRIMINATOR];
-
-        let num_accounts = 2 + multisig_accounts.len();
-
-        // build instruction for Pausable
         let instruction = InstructionView {
-            program_id: token_program,
-            data,
-            accounts: unsafe {
-                // create a slice &[] by providing the pointer to that data and the length of the data
-                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
-            },
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 34) },
         };
 
-        // Account view array
-        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
-        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 2 + MAX_MULTISIG_SIGNERS];
-
-        // SAFETY:
-        // - `account_views` is sized to 2 + MAX_MULTISIG_SIGNERS
-        unsafe {
-            // - Index 0 is always present
-            account_views.get_unchecked_mut(0).write(token_account);
-            // - Index 1 is always present
-            account_views.get_unchecked_mut(1).write(authority);
-        }
-
-        // Fill signer accounts
-        for (account_view, signer) in account_views[2..].iter_mut().zip(multisig_accounts.iter()) {
-            account_view.write(signer);
-        }
-
-        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
-            &instruction,
-            unsafe {
-                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
-            },
-            signers,
-        )
+        invoke(&instruction, &[self.mint])
     }
 }
@@ -10,24 +10,24 @@ use {
     solana_program_error::{ProgramError, ProgramResult},
 };
 
-/// Pause a token account.
+/// Pause minting, burning and transferring for the mint.
 ///
 /// Expected accounts:
 ///
 /// **Single authority**
 ///
-/// 0. `[writable]` The token account to pause.
-/// 1. `[signer]` The pause authority of the token account.
+/// 0. `[writable]` The mint to pause.
+/// 1. `[signer]` The pause authority of the mint.
 ///
 /// **Multisignature authority**
 ///
-/// 0. `[writable]` The token account to pause.
-/// 1. `[readonly]` The multisig account that has pause authority.
+/// 0. `[writable]` The mint to pause.
+/// 1. `[readonly]` The mint's multisignature pause authority.
 /// 2. `[signer]` M signer accounts (as required by the multisig).
 pub struct Pause<'a, 'b, 'c> {
-    /// The token account to pause.
-    pub token_account: &'a AccountView,
-    /// The pause authority of the token account.
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The pause authority (single or multisig).
     pub authority: &'a AccountView,
     /// Signer accounts if the authority is a multisig.
     pub signers: &'c [&'a AccountView],
@@ -46,7 +46,7 @@ impl Pause<'_, '_, '_> {
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
         let &Self {
-            token_account,
+            mint,
             authority,
             signers: multisig_accounts,
             token_program,
@@ -58,7 +58,7 @@ impl Pause<'_, '_, '_> {
         }
 
         // creates an array of uninitialized InstructionAccount with 2 + MAX_MULTISIG_SIGNERS
-        // i.e. [token_account + authority](2) + signers(max_multisig_signers)
+        // i.e. [mint + authority](2) + signers(max_multisig_signers)
         const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
             MaybeUninit::<InstructionAccount>::uninit();
         let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 2 + MAX_MULTISIG_SIGNERS];
@@ -66,10 +66,10 @@ impl Pause<'_, '_, '_> {
         // SAFETY:
         // - `instruction_accounts` is sized to 2 + MAX_MULTISIG_SIGNERS
         unsafe {
-            // - Index 0 is always present (TokenAccount)
+            // - Index 0 is always present (Mint)
             instruction_accounts
                 .get_unchecked_mut(0)
-                .write(InstructionAccount::writable(token_account.address()));
+                .write(InstructionAccount::writable(mint.address()));
 
             // - Index 1 is always present (Authority)
             instruction_accounts
@@ -113,7 +113,7 @@ impl Pause<'_, '_, '_> {
         // - `account_views` is sized to 2 + MAX_MULTISIG_SIGNERS
         unsafe {
             // - Index 0 is always present
-            account_views.get_unchecked_mut(0).write(token_account);
+            account_views.get_unchecked_mut(0).write(mint);
             // - Index 1 is always present
             account_views.get_unchecked_mut(1).write(authority);
         }
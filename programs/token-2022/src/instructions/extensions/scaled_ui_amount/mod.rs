@@ -0,0 +1,5 @@
+pub mod initialize;
+pub mod update_multiplier;
+
+pub use initialize::*;
+pub use update_multiplier::*;
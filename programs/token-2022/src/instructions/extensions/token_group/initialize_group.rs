@@ -0,0 +1,85 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Initialize a new token group.
+///
+/// This is an instruction of the SPL Token Group interface, implemented by
+/// Token-2022 for mints with the `TokenGroup` extension.
+///
+/// Assumes one has already initialized a mint for the group.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Group.
+///   1. `[]` Mint.
+///   2. `[SIGNER]` Mint authority.
+pub struct InitializeGroup<'a, 'b> {
+    /// Group Account (the mint for Token-2022).
+    pub group: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Mint authority Account.
+    pub mint_authority: &'a AccountView,
+    /// Update authority for the group.
+    pub update_authority: Option<&'b Address>,
+    /// The maximum number of group members.
+    pub max_size: u64,
+    /// Group Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeGroup<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [121, 113, 108, 39, 54, 51, 0, 4];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 3] = [
+            InstructionAccount::writable(self.group.address()),
+            InstructionAccount::readonly(self.mint.address()),
+            InstructionAccount::readonly_signer(self.mint_authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, [u8; 8])
+        // -  [8..40]: update_authority (32 bytes, Address; zeroed if `None`)
+        // -  [40..48]: max_size (8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 48];
+
+        // Set discriminator as [u8; 8] at offset [0..8]
+        write_bytes(&mut instruction_data, &Self::DISCRIMINATOR);
+        // Set update_authority as Address at offset [8..40]
+        if let Some(update_authority) = self.update_authority {
+            write_bytes(&mut instruction_data[8..40], update_authority.as_array());
+        } else {
+            write_bytes(&mut instruction_data[8..40], &[0; 32]);
+        }
+        // Set max_size as u64 at offset [40..48]
+        write_bytes(&mut instruction_data[40..], &self.max_size.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 48) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.group, self.mint, self.mint_authority],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,71 @@
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+/// Initialize a new member of a token group.
+///
+/// Assumes the token group has been initialized.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Member.
+///   1. `[]` Member mint.
+///   2. `[SIGNER]` Member mint authority.
+///   3. `[WRITE]` Group.
+///   4. `[SIGNER]` Group update authority.
+pub struct InitializeMember<'a, 'b> {
+    /// Member Account (the member mint for Token-2022).
+    pub member: &'a AccountView,
+    /// Member mint Account.
+    pub member_mint: &'a AccountView,
+    /// Member mint authority Account.
+    pub member_mint_authority: &'a AccountView,
+    /// Group Account.
+    pub group: &'a AccountView,
+    /// Group update authority Account.
+    pub group_update_authority: &'a AccountView,
+    /// Group Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeMember<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [152, 32, 222, 176, 223, 237, 116, 134];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 5] = [
+            InstructionAccount::writable(self.member.address()),
+            InstructionAccount::readonly(self.member_mint.address()),
+            InstructionAccount::readonly_signer(self.member_mint_authority.address()),
+            InstructionAccount::writable(self.group.address()),
+            InstructionAccount::readonly_signer(self.group_update_authority.address()),
+        ];
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: &Self::DISCRIMINATOR,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.member,
+                self.member_mint,
+                self.member_mint_authority,
+                self.group,
+                self.group_update_authority,
+            ],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,9 @@
+pub mod initialize_group;
+pub mod initialize_member;
+pub mod update_group_authority;
+pub mod update_group_max_size;
+
+pub use initialize_group::*;
+pub use initialize_member::*;
+pub use update_group_authority::*;
+pub use update_group_max_size::*;
@@ -0,0 +1,63 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Update the max size of a token group.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Group.
+///   1. `[SIGNER]` Update authority.
+pub struct UpdateGroupMaxSize<'a, 'b> {
+    /// Group Account (the mint for Token-2022).
+    pub group: &'a AccountView,
+    /// Update authority Account.
+    pub update_authority: &'a AccountView,
+    /// New max size for the group.
+    pub max_size: u64,
+    /// Group Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl UpdateGroupMaxSize<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [108, 37, 171, 143, 248, 30, 18, 110];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.group.address()),
+            InstructionAccount::readonly_signer(self.update_authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, [u8; 8])
+        // -  [8..16]: max_size (8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 16];
+
+        // Set discriminator as [u8; 8] at offset [0..8]
+        write_bytes(&mut instruction_data, &Self::DISCRIMINATOR);
+        // Set max_size as u64 at offset [8..16]
+        write_bytes(&mut instruction_data[8..], &self.max_size.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 16) },
+        };
+
+        invoke_signed(&instruction, &[self.group, self.update_authority], signers)
+    }
+}
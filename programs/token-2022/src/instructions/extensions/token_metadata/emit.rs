@@ -0,0 +1,73 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Emit the token metadata as return data.
+///
+/// The format of the data emitted follows exactly the `TokenMetadata` struct,
+/// but it's possible that the account data is stored in another format by the
+/// program.
+///
+/// ### Accounts:
+///   0. `[]` Metadata account.
+pub struct Emit<'a, 'b> {
+    /// Metadata Account (the mint for Token-2022).
+    pub metadata: &'a AccountView,
+    /// Start of range of data to emit.
+    pub start: Option<u64>,
+    /// End of range of data to emit.
+    pub end: Option<u64>,
+    /// Metadata Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl Emit<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [250, 166, 180, 250, 13, 12, 184, 70];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::readonly(self.metadata.address())];
+
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, [u8; 8])
+        // -  [..]: start presence flag (1 byte, u8)
+        // -  [..]: start (optional, 8 bytes, u64)
+        // -  [..]: end presence flag (1 byte, u8)
+        // -  [..]: end (optional, 8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 26];
+
+        // Set discriminator as [u8; 8] at offset [0..8]
+        write_bytes(&mut instruction_data, &Self::DISCRIMINATOR);
+
+        let mut length = 8;
+
+        for value in [self.start, self.end] {
+            if let Some(value) = value {
+                write_bytes(&mut instruction_data[length..length + 1], &[1]);
+                write_bytes(
+                    &mut instruction_data[length + 1..length + 9],
+                    &value.to_le_bytes(),
+                );
+                length += 9;
+            } else {
+                write_bytes(&mut instruction_data[length..length + 1], &[0]);
+                length += 1;
+            }
+        }
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
+        };
+
+        invoke(&instruction, &[self.metadata])
+    }
+}
@@ -0,0 +1,96 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use super::{write_str, MAX_INSTRUCTION_DATA_LEN};
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Initialize the basic token metadata fields.
+///
+/// This is an instruction of the SPL Token Metadata interface, implemented by
+/// Token-2022 for mints with the `TokenMetadata` extension.
+///
+/// Assumes that the provided mint is an SPL token mint, that the metadata
+/// account is allocated and assigned to the program, and that the metadata
+/// account has enough lamports to cover the rent-exempt reserve.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Metadata.
+///   1. `[]` Update authority.
+///   2. `[]` Mint.
+///   3. `[SIGNER]` Mint authority.
+pub struct Initialize<'a, 'b> {
+    /// Metadata Account (the mint for Token-2022).
+    pub metadata: &'a AccountView,
+    /// Update authority Account.
+    pub update_authority: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Mint authority Account.
+    pub mint_authority: &'a AccountView,
+    /// Longer name of the token.
+    pub name: &'b str,
+    /// Shortened symbol of the token.
+    pub symbol: &'b str,
+    /// URI pointing to richer metadata.
+    pub uri: &'b str,
+    /// Metadata Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl Initialize<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 4] = [
+            InstructionAccount::writable(self.metadata.address()),
+            InstructionAccount::readonly(self.update_authority.address()),
+            InstructionAccount::readonly(self.mint.address()),
+            InstructionAccount::readonly_signer(self.mint_authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, [u8; 8])
+        // -  [..]: name (4 bytes length + name bytes)
+        // -  [..]: symbol (4 bytes length + symbol bytes)
+        // -  [..]: uri (4 bytes length + uri bytes)
+        let mut instruction_data = [UNINIT_BYTE; MAX_INSTRUCTION_DATA_LEN];
+
+        // Set discriminator as [u8; 8] at offset [0..8]
+        write_bytes(&mut instruction_data, &Self::DISCRIMINATOR);
+
+        let offset = write_str(&mut instruction_data, 8, self.name)?;
+        let offset = write_str(&mut instruction_data, offset, self.symbol)?;
+        let length = write_str(&mut instruction_data, offset, self.uri)?;
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.metadata,
+                self.update_authority,
+                self.mint,
+                self.mint_authority,
+            ],
+            signers,
+        )
+    }
+}
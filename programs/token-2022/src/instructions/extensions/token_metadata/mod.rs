@@ -0,0 +1,47 @@
+pub mod emit;
+pub mod initialize;
+pub mod remove_key;
+pub mod update_authority;
+pub mod update_field;
+
+pub use emit::*;
+pub use initialize::*;
+pub use remove_key::*;
+pub use update_authority::*;
+pub use update_field::*;
+
+use core::mem::MaybeUninit;
+
+use solana_program_error::ProgramError;
+
+use crate::write_bytes;
+
+/// Maximum length of the instruction data of variable-length token metadata
+/// instructions.
+///
+/// The instruction data is assembled on the stack, so the total length of the
+/// strings of an instruction is bounded by this value.
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 512;
+
+/// Write a borsh-encoded string (a `u32` length followed by its bytes) at `offset`,
+/// returning the offset after it.
+#[inline(always)]
+pub(crate) fn write_str(
+    data: &mut [MaybeUninit<u8>],
+    offset: usize,
+    value: &str,
+) -> Result<usize, ProgramError> {
+    let end = offset + 4 + value.len();
+
+    if end > data.len() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    write_bytes(
+        &mut data[offset..offset + 4],
+        &(value.len() as u32).to_le_bytes(),
+    );
+    write_bytes(&mut data[offset + 4..end], value.as_bytes());
+
+    Ok(end)
+}
@@ -0,0 +1,77 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use super::{write_str, MAX_INSTRUCTION_DATA_LEN};
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Remove a key-value pair in a token metadata account.
+///
+/// This only applies to additional fields, and not the base name / symbol /
+/// URI fields.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Metadata account.
+///   1. `[SIGNER]` Update authority.
+pub struct RemoveKey<'a, 'b> {
+    /// Metadata Account (the mint for Token-2022).
+    pub metadata: &'a AccountView,
+    /// Update authority Account.
+    pub update_authority: &'a AccountView,
+    /// If the idempotent flag is set to `true`, then the instruction will not
+    /// error if the key does not exist.
+    pub idempotent: bool,
+    /// Key to remove in the additional metadata portion.
+    pub key: &'b str,
+    /// Metadata Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl RemoveKey<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.metadata.address()),
+            InstructionAccount::readonly_signer(self.update_authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, [u8; 8])
+        // -  [8]: idempotent (1 byte, bool)
+        // -  [9..]: key (4 bytes length + key bytes)
+        let mut instruction_data = [UNINIT_BYTE; MAX_INSTRUCTION_DATA_LEN];
+
+        // Set discriminator as [u8; 8] at offset [0..8]
+        write_bytes(&mut instruction_data, &Self::DISCRIMINATOR);
+        // Set idempotent as u8 at offset [8]
+        write_bytes(&mut instruction_data[8..9], &[self.idempotent as u8]);
+
+        let length = write_str(&mut instruction_data, 9, self.key)?;
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
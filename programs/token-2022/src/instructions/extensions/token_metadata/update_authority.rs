@@ -0,0 +1,71 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Update the token metadata authority.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Metadata account.
+///   1. `[SIGNER]` Current update authority.
+pub struct UpdateAuthority<'a, 'b> {
+    /// Metadata Account (the mint for Token-2022).
+    pub metadata: &'a AccountView,
+    /// Current update authority Account.
+    pub update_authority: &'a AccountView,
+    /// New authority, or `None` to make the metadata immutable.
+    pub new_authority: Option<&'b Address>,
+    /// Metadata Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl UpdateAuthority<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.metadata.address()),
+            InstructionAccount::readonly_signer(self.update_authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, [u8; 8])
+        // -  [8..40]: new_authority (32 bytes, Address; zeroed if `None`)
+        let mut instruction_data = [UNINIT_BYTE; 40];
+
+        // Set discriminator as [u8; 8] at offset [0..8]
+        write_bytes(&mut instruction_data, &Self::DISCRIMINATOR);
+        // Set new_authority as Address at offset [8..40]
+        if let Some(new_authority) = self.new_authority {
+            write_bytes(&mut instruction_data[8..], new_authority.as_array());
+        } else {
+            write_bytes(&mut instruction_data[8..], &[0; 32]);
+        }
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 40) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
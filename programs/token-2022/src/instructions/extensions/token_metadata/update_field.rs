@@ -0,0 +1,106 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{
+    cpi::{invoke_signed, Signer},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::ProgramResult;
+
+use super::{write_str, MAX_INSTRUCTION_DATA_LEN};
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Fields in the metadata account, used for updating.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Field<'a> {
+    /// The name field, corresponding to `TokenMetadata.name`.
+    Name,
+    /// The symbol field, corresponding to `TokenMetadata.symbol`.
+    Symbol,
+    /// The uri field, corresponding to `TokenMetadata.uri`.
+    Uri,
+    /// A user field, whose key is given by the associated string.
+    Key(&'a str),
+}
+
+/// Update a field in a token metadata account.
+///
+/// If the field does not exist on the account, it will be created. If the field
+/// does exist, it will be overwritten.
+///
+/// ### Accounts:
+///   0. `[WRITE]` Metadata account.
+///   1. `[SIGNER]` Update authority.
+pub struct UpdateField<'a, 'b> {
+    /// Metadata Account (the mint for Token-2022).
+    pub metadata: &'a AccountView,
+    /// Update authority Account.
+    pub update_authority: &'a AccountView,
+    /// Field to update in the metadata.
+    pub field: Field<'b>,
+    /// Value to write for the field.
+    pub value: &'b str,
+    /// Metadata Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl UpdateField<'_, '_> {
+    pub const DISCRIMINATOR: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 2] = [
+            InstructionAccount::writable(self.metadata.address()),
+            InstructionAccount::readonly_signer(self.update_authority.address()),
+        ];
+
+        // Instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, [u8; 8])
+        // -  [8]: field variant (1 byte, u8)
+        // -  [..]: key (optional, 4 bytes length + key bytes)
+        // -  [..]: value (4 bytes length + value bytes)
+        let mut instruction_data = [UNINIT_BYTE; MAX_INSTRUCTION_DATA_LEN];
+
+        // Set discriminator as [u8; 8] at offset [0..8]
+        write_bytes(&mut instruction_data, &Self::DISCRIMINATOR);
+
+        let offset = match self.field {
+            Field::Name => {
+                write_bytes(&mut instruction_data[8..9], &[0]);
+                9
+            }
+            Field::Symbol => {
+                write_bytes(&mut instruction_data[8..9], &[1]);
+                9
+            }
+            Field::Uri => {
+                write_bytes(&mut instruction_data[8..9], &[2]);
+                9
+            }
+            Field::Key(key) => {
+                write_bytes(&mut instruction_data[8..9], &[3]);
+                write_str(&mut instruction_data, 9, key)?
+            }
+        };
+        let length = write_str(&mut instruction_data, offset, self.value)?;
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
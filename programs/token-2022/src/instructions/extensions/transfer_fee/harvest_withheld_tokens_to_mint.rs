@@ -0,0 +1,100 @@
+use {
+    crate::instructions::extensions::ExtensionDiscriminator,
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_with_bounds, MAX_STATIC_CPI_ACCOUNTS},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Permissionless instruction to transfer all withheld tokens to the mint.
+///
+/// Succeeds for frozen accounts. Accounts provided that are not owned by the
+/// mint are skipped by the program.
+///
+/// Expected accounts:
+///
+/// 0. `[writable]` The mint.
+/// 1. `..1+N` `[writable]` The source accounts to harvest from.
+pub struct HarvestWithheldTokensToMint<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The source accounts to harvest from.
+    pub sources: &'c [&'a AccountView],
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl HarvestWithheldTokensToMint<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 4;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        let &Self {
+            mint,
+            sources,
+            token_program,
+        } = self;
+
+        let num_accounts = 1 + sources.len();
+
+        if num_accounts > MAX_STATIC_CPI_ACCOUNTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; MAX_STATIC_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to MAX_STATIC_CPI_ACCOUNTS
+            // - Index 0 is always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(mint.address()));
+        }
+
+        for (instruction_account, source) in
+            instruction_accounts[1..].iter_mut().zip(sources.iter())
+        {
+            instruction_account.write(InstructionAccount::writable(source.address()));
+        }
+
+        let data = &[
+            ExtensionDiscriminator::TransferFee as u8,
+            Self::DISCRIMINATOR,
+        ];
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data,
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; MAX_STATIC_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to MAX_STATIC_CPI_ACCOUNTS
+            // - Index 0 is always present
+            account_views.get_unchecked_mut(0).write(mint);
+        }
+
+        // Fill source accounts
+        for (account_view, source) in account_views[1..].iter_mut().zip(sources.iter()) {
+            account_view.write(source);
+        }
+
+        invoke_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(&instruction, unsafe {
+            slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{instructions::extensions::ExtensionDiscriminator, write_bytes, UNINIT_BYTE};
+
+/// Initialize the transfer fee on a new mint.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializeTransferFeeConfig<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Authority that can set the fee.
+    pub transfer_fee_config_authority: Option<&'b Address>,
+    /// Authority that can withdraw withheld fees.
+    pub withdraw_withheld_authority: Option<&'b Address>,
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount.
+    pub transfer_fee_basis_points: u16,
+    /// Maximum fee assessed on transfers.
+    pub maximum_fee: u64,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeTransferFeeConfig<'_, '_> {
+    pub const DISCRIMINATOR: u8 = 0;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2]: transfer_fee_config_authority presence flag (1 byte, u8)
+        // -  [3..35]: transfer_fee_config_authority (optional, 32 bytes, Address)
+        // -  [..]: withdraw_withheld_authority presence flag (1 byte, u8)
+        // -  [..]: withdraw_withheld_authority (optional, 32 bytes, Address)
+        // -  [..]: transfer_fee_basis_points (2 bytes, u16)
+        // -  [..]: maximum_fee (8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 78];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::TransferFee as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+
+        let mut offset = 2;
+
+        for authority in [
+            self.transfer_fee_config_authority,
+            self.withdraw_withheld_authority,
+        ] {
+            if let Some(authority) = authority {
+                write_bytes(&mut instruction_data[offset..offset + 1], &[1]);
+                write_bytes(
+                    &mut instruction_data[offset + 1..offset + 33],
+                    authority.as_array(),
+                );
+                offset += 33;
+            } else {
+                write_bytes(&mut instruction_data[offset..offset + 1], &[0]);
+                offset += 1;
+            }
+        }
+
+        // Set transfer_fee_basis_points as u16
+        write_bytes(
+            &mut instruction_data[offset..offset + 2],
+            &self.transfer_fee_basis_points.to_le_bytes(),
+        );
+        // Set maximum_fee as u64
+        write_bytes(
+            &mut instruction_data[offset + 2..offset + 10],
+            &self.maximum_fee.to_le_bytes(),
+        );
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, offset + 10) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
@@ -0,0 +1,171 @@
+use {
+    crate::{
+        instructions::{extensions::ExtensionDiscriminator, MAX_MULTISIG_SIGNERS},
+        write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Transfer tokens from one account to another, asserting the expected fee
+/// of the transfer.
+///
+/// Expected accounts:
+///
+/// **Single owner/delegate**
+/// 0. `[writable]` The source account.
+/// 1. `[]` The token mint.
+/// 2. `[writable]` The destination account.
+/// 3. `[signer]` The source account's owner/delegate.
+///
+/// **Multisignature owner/delegate**
+/// 0. `[writable]` The source account.
+/// 1. `[]` The token mint.
+/// 2. `[writable]` The destination account.
+/// 3. `[readonly]` The source account's multisignature owner/delegate.
+/// 4. `[signer]` M signer accounts (as required by the multisig).
+pub struct TransferCheckedWithFee<'a, 'b, 'c> {
+    /// Sender account.
+    pub from: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Recipient account.
+    pub to: &'a AccountView,
+    /// Authority account (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token.
+    pub decimals: u8,
+    /// Expected fee assessed on this transfer.
+    pub fee: u64,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl TransferCheckedWithFee<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            from,
+            mint,
+            to,
+            authority,
+            signers: multisig_accounts,
+            token_program,
+            ..
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 3 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(from.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::readonly(mint.address()));
+            instruction_accounts
+                .get_unchecked_mut(2)
+                .write(InstructionAccount::writable(to.address()));
+            instruction_accounts
+                .get_unchecked_mut(3)
+                .write(InstructionAccount::new(
+                    authority.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+        }
+
+        for (instruction_account, signer) in instruction_accounts[4..]
+            .iter_mut()
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2..10]: amount (8 bytes, u64)
+        // -  [10]: decimals (1 byte, u8)
+        // -  [11..19]: fee (8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 19];
+
+        // Set extension and instruction discriminators as u8 at offset [0..2]
+        write_bytes(
+            &mut instruction_data,
+            &[
+                ExtensionDiscriminator::TransferFee as u8,
+                Self::DISCRIMINATOR,
+            ],
+        );
+        // Set amount as u64 at offset [2..10]
+        write_bytes(&mut instruction_data[2..10], &self.amount.to_le_bytes());
+        // Set decimals as u8 at offset [10]
+        write_bytes(&mut instruction_data[10..11], &[self.decimals]);
+        // Set fee as u64 at offset [11..19]
+        write_bytes(&mut instruction_data[11..], &self.fee.to_le_bytes());
+
+        let num_accounts = 4 + multisig_accounts.len();
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 19) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 3 are always present
+            account_views.get_unchecked_mut(0).write(from);
+            account_views.get_unchecked_mut(1).write(mint);
+            account_views.get_unchecked_mut(2).write(to);
+            account_views.get_unchecked_mut(3).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_view, signer) in account_views[4..].iter_mut().zip(multisig_accounts.iter()) {
+            account_view.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,156 @@
+use {
+    crate::{instructions::extensions::ExtensionDiscriminator, instructions::MAX_MULTISIG_SIGNERS},
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer, MAX_STATIC_CPI_ACCOUNTS},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Transfer all withheld tokens to an account from a list of token accounts.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[]` The token mint.
+/// 1. `[writable]` The fee receiver account.
+/// 2. `[signer]` The mint's `withdraw_withheld_authority`.
+/// 3. `..3+N` `[writable]` The source accounts to withdraw from.
+///
+/// **Multisignature authority**
+/// 0. `[]` The token mint.
+/// 1. `[writable]` The fee receiver account.
+/// 2. `[readonly]` The mint's multisig `withdraw_withheld_authority`.
+/// 3. `..3+M` `[signer]` M signer accounts (as required by the multisig).
+/// 4. `3+M..3+M+N` `[writable]` The source accounts to withdraw from.
+pub struct WithdrawWithheldTokensFromAccounts<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// The fee receiver account.
+    pub destination: &'a AccountView,
+    /// The withdraw withheld authority (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// The source accounts to withdraw from.
+    pub sources: &'c [&'a AccountView],
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl WithdrawWithheldTokensFromAccounts<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 3;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            mint,
+            destination,
+            authority,
+            signers: multisig_accounts,
+            sources,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let num_accounts = 3 + multisig_accounts.len() + sources.len();
+
+        if num_accounts > MAX_STATIC_CPI_ACCOUNTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; MAX_STATIC_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to MAX_STATIC_CPI_ACCOUNTS
+            // - Index 0 to 2 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::readonly(mint.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::writable(destination.address()));
+            instruction_accounts
+                .get_unchecked_mut(2)
+                .write(InstructionAccount::new(
+                    authority.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+        }
+
+        let (signer_accounts, source_accounts) =
+            instruction_accounts[3..].split_at_mut(multisig_accounts.len());
+
+        for (instruction_account, signer) in
+            signer_accounts.iter_mut().zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        for (instruction_account, source) in source_accounts.iter_mut().zip(sources.iter()) {
+            instruction_account.write(InstructionAccount::writable(source.address()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: extension discriminator (1 byte, u8)
+        // -  [1]: instruction discriminator (1 byte, u8)
+        // -  [2]: number of source accounts (1 byte, u8)
+        let data = &[
+            ExtensionDiscriminator::TransferFee as u8,
+            Self::DISCRIMINATOR,
+            sources.len() as u8,
+        ];
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data,
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; MAX_STATIC_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to MAX_STATIC_CPI_ACCOUNTS
+            // - Index 0 to 2 are always present
+            account_views.get_unchecked_mut(0).write(mint);
+            account_views.get_unchecked_mut(1).write(destination);
+            account_views.get_unchecked_mut(2).write(authority);
+        }
+
+        // Fill signer and source accounts
+        for (account_view, account) in account_views[3..]
+            .iter_mut()
+            .zip(multisig_accounts.iter().chain(sources.iter()))
+        {
+            account_view.write(account);
+        }
+
+        invoke_signed_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,11 @@
+pub mod initialize;
+pub mod transfer_checked_with_hook;
+pub mod update;
+
+pub use initialize::*;
+pub use transfer_checked_with_hook::*;
+pub use update::*;
+
+/// Discriminator of the transfer hook interface `Execute` instruction, also
+/// used to locate its `ExtraAccountMetaList` in the validation state account.
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
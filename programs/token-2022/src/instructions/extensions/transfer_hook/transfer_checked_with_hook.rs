@@ -0,0 +1,265 @@
+use {
+    super::EXECUTE_DISCRIMINATOR,
+    crate::{
+        instructions::MAX_MULTISIG_SIGNERS, state::ExtraAccountMetaList, write_bytes, UNINIT_BYTE,
+    },
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer, MAX_STATIC_CPI_ACCOUNTS},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Number of accounts of the transfer hook `Execute` instruction preceding
+/// the extra accounts.
+const EXECUTE_ACCOUNTS_LEN: usize = 5;
+
+/// Transfer tokens of a mint with the `TransferHook` extension, resolving the
+/// extra accounts required by the transfer hook program from its validation
+/// state account.
+///
+/// The extra accounts are looked up by address in `additional_accounts`, which
+/// must contain every account resolved from the `ExtraAccountMetaList`.
+///
+/// Expected accounts:
+///
+/// **Single authority**
+/// 0. `[writable]` The source account.
+/// 1. `[]` The token mint.
+/// 2. `[writable]` The destination account.
+/// 3. `[signer]` The source account's owner/delegate.
+/// 4. `..4+N` The extra accounts required by the transfer hook program.
+/// 5. `4+N` `[]` The transfer hook program.
+/// 6. `5+N` `[]` The validation state account of the transfer hook program.
+///
+/// **Multisignature authority**
+/// 0. `[writable]` The source account.
+/// 1. `[]` The token mint.
+/// 2. `[writable]` The destination account.
+/// 3. `[]` The source account's multisignature owner/delegate.
+/// 4. `..4+M` `[signer]` M signer accounts (as required by the multisig).
+/// 5. `4+M..4+M+N` The extra accounts required by the transfer hook program.
+/// 6. `4+M+N` `[]` The transfer hook program.
+/// 7. `5+M+N` `[]` The validation state account of the transfer hook program.
+pub struct TransferCheckedWithHook<'a, 'b, 'c> {
+    /// Sender account.
+    pub from: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Recipient account.
+    pub to: &'a AccountView,
+    /// Authority account (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token.
+    pub decimals: u8,
+    /// Transfer hook program.
+    pub hook_program: &'a AccountView,
+    /// Validation state account of the transfer hook program.
+    pub validation_state: &'a AccountView,
+    /// Accounts to resolve the extra accounts from.
+    pub additional_accounts: &'c [&'a AccountView],
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl TransferCheckedWithHook<'_, '_, '_> {
+    pub const DISCRIMINATOR: u8 = 12;
+
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            from,
+            mint,
+            to,
+            authority,
+            signers: multisig_accounts,
+            amount,
+            decimals,
+            hook_program,
+            validation_state,
+            additional_accounts,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; MAX_STATIC_CPI_ACCOUNTS];
+
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; MAX_STATIC_CPI_ACCOUNTS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` and `account_views` are sized to MAX_STATIC_CPI_ACCOUNTS
+            // - Index 0 to 3 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(from.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::readonly(mint.address()));
+            instruction_accounts
+                .get_unchecked_mut(2)
+                .write(InstructionAccount::writable(to.address()));
+            instruction_accounts
+                .get_unchecked_mut(3)
+                .write(InstructionAccount::new(
+                    authority.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+
+            account_views.get_unchecked_mut(0).write(from);
+            account_views.get_unchecked_mut(1).write(mint);
+            account_views.get_unchecked_mut(2).write(to);
+            account_views.get_unchecked_mut(3).write(authority);
+        }
+
+        // Fill signer accounts
+        for ((instruction_account, account_view), signer) in instruction_accounts[4..]
+            .iter_mut()
+            .zip(account_views[4..].iter_mut())
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+            account_view.write(signer);
+        }
+
+        let extras_offset = 4 + multisig_accounts.len();
+
+        // Transfer hook `Execute` instruction data layout:
+        // -  [0..8]: instruction discriminator (8 bytes, u8)
+        // -  [8..16]: amount (8 bytes, u64)
+        let mut execute_data = [0; 16];
+        execute_data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        execute_data[8..].copy_from_slice(&amount.to_le_bytes());
+
+        // Accounts of the `Execute` instruction, which extra account seeds can
+        // reference by index.
+        let mut execute_accounts = [from; MAX_STATIC_CPI_ACCOUNTS];
+        execute_accounts[..EXECUTE_ACCOUNTS_LEN].copy_from_slice(&[
+            from,
+            mint,
+            to,
+            authority,
+            validation_state,
+        ]);
+
+        let num_extras = {
+            let data = validation_state.try_borrow()?;
+            let extra_account_metas =
+                ExtraAccountMetaList::from_account_data(&data, &EXECUTE_DISCRIMINATOR)?;
+
+            // Extra accounts are followed by the hook program and validation state.
+            if extras_offset + extra_account_metas.len() + 2 > MAX_STATIC_CPI_ACCOUNTS {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            for (index, meta) in extra_account_metas.metas().iter().enumerate() {
+                let resolved_accounts = &execute_accounts[..EXECUTE_ACCOUNTS_LEN + index];
+                let address =
+                    meta.resolve(&execute_data, hook_program.address(), resolved_accounts)?;
+
+                let mut is_signer = meta.is_signer();
+                let mut is_writable = meta.is_writable();
+
+                // An account already present in the `Execute` instruction cannot be
+                // escalated beyond the highest privilege it has there.
+                let mut found = false;
+                let (mut max_signer, mut max_writable) = (false, false);
+
+                for (position, account) in resolved_accounts.iter().enumerate() {
+                    if account.address() == &address {
+                        found = true;
+
+                        if position >= EXECUTE_ACCOUNTS_LEN {
+                            // SAFETY: extra accounts resolved before this one are initialized.
+                            let previous = unsafe {
+                                instruction_accounts
+                                    [extras_offset + position - EXECUTE_ACCOUNTS_LEN]
+                                    .assume_init_ref()
+                            };
+                            max_signer |= previous.is_signer;
+                            max_writable |= previous.is_writable;
+                        }
+                    }
+                }
+
+                if found {
+                    is_signer &= max_signer;
+                    is_writable &= max_writable;
+                }
+
+                let account = additional_accounts
+                    .iter()
+                    .find(|account| account.address() == &address)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+                execute_accounts[EXECUTE_ACCOUNTS_LEN + index] = account;
+                instruction_accounts[extras_offset + index].write(InstructionAccount::new(
+                    account.address(),
+                    is_writable,
+                    is_signer,
+                ));
+                account_views[extras_offset + index].write(account);
+            }
+
+            extra_account_metas.len()
+        };
+
+        let hook_offset = extras_offset + num_extras;
+
+        instruction_accounts[hook_offset]
+            .write(InstructionAccount::readonly(hook_program.address()));
+        instruction_accounts[hook_offset + 1]
+            .write(InstructionAccount::readonly(validation_state.address()));
+        account_views[hook_offset].write(hook_program);
+        account_views[hook_offset + 1].write(validation_state);
+
+        let num_accounts = hook_offset + 2;
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        // -  [9]: decimals (1 byte, u8)
+        let mut instruction_data = [UNINIT_BYTE; 10];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[Self::DISCRIMINATOR]);
+        // Set amount as u64 at offset [1..9]
+        write_bytes(&mut instruction_data[1..9], &amount.to_le_bytes());
+        // Set decimals as u8 at offset [9]
+        write_bytes(&mut instruction_data[9..], &[decimals]);
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, 10) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        invoke_signed_with_bounds::<MAX_STATIC_CPI_ACCOUNTS>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
@@ -0,0 +1,85 @@
+use {
+    super::reallocate::MAX_EXTENSION_TYPES,
+    crate::{state::ExtensionType, write_bytes, UNINIT_BYTE},
+    core::slice::from_raw_parts,
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{get_return_data, invoke},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Get the required account data size of a token account for the given
+/// mint, including the extensions required by the mint and the additional
+/// extension types requested.
+///
+/// The size is returned by the program through return data.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub struct GetAccountDataSize<'a, 'b, 'c> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Additional account extension types to include in the size.
+    pub extension_types: &'c [ExtensionType],
+    /// Token Program
+    pub token_program: &'b Address,
+}
+
+impl GetAccountDataSize<'_, '_, '_> {
+    /// Invoke the instruction, returning the account data size.
+    ///
+    /// Returns `ProgramError::InvalidAccountData` if the program did not return
+    /// a valid size.
+    #[inline(always)]
+    pub fn invoke(&self) -> Result<u64, ProgramError> {
+        self.invoke_without_return_data()?;
+
+        get_return_data()
+            .filter(|return_data| return_data.program_id() == self.token_program)
+            .and_then(|return_data| return_data.as_slice().try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    fn invoke_without_return_data(&self) -> ProgramResult {
+        if self.extension_types.len() > MAX_EXTENSION_TYPES {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::readonly(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..1+2N]: extension types (2 bytes each, u16)
+        let mut instruction_data = [UNINIT_BYTE; 1 + 2 * MAX_EXTENSION_TYPES];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[21]);
+        // Set extension types as u16 at offset [1..1+2N]
+        for (data, extension_type) in instruction_data[1..]
+            .chunks_exact_mut(2)
+            .zip(self.extension_types.iter())
+        {
+            write_bytes(data, &(*extension_type as u16).to_le_bytes());
+        }
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe {
+                from_raw_parts(
+                    instruction_data.as_ptr() as _,
+                    1 + 2 * self.extension_types.len(),
+                )
+            },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
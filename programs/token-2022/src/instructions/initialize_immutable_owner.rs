@@ -0,0 +1,34 @@
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+/// Initialize the immutable owner extension on a new token account.
+///
+/// This instruction must be invoked before `InitializeAccount`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to initialize.
+pub struct InitializeImmutableOwner<'a, 'b> {
+    /// Token Account.
+    pub account: &'a AccountView,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeImmutableOwner<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.account.address())];
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: &[22],
+        };
+
+        invoke(&instruction, &[self.account])
+    }
+}
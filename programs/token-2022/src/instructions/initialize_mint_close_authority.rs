@@ -0,0 +1,61 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Initialize the close account authority on a new mint.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializeMintCloseAuthority<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Authority that must sign the `CloseAccount` instruction on the mint.
+    pub close_authority: Option<&'b Address>,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializeMintCloseAuthority<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1]: close_authority presence flag (1 byte, u8)
+        // -  [2..34]: close_authority (optional, 32 bytes, Address)
+        let mut instruction_data = [UNINIT_BYTE; 34];
+        let mut length = instruction_data.len();
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[25]);
+
+        if let Some(close_authority) = self.close_authority {
+            // Set Option = `true` & close_authority at offset [1..34]
+            write_bytes(&mut instruction_data[1..2], &[1]);
+            write_bytes(&mut instruction_data[2..], close_authority.as_array());
+        } else {
+            // Set Option = `false`
+            write_bytes(&mut instruction_data[1..2], &[0]);
+            // Adjust length if no close authority
+            length = 2;
+        }
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
@@ -0,0 +1,50 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Initialize the permanent delegate on a new mint.
+///
+/// This instruction must be invoked before `InitializeMint`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint to initialize.
+pub struct InitializePermanentDelegate<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Authority that may sign for `Transfer`s and `Burn`s on any account.
+    pub delegate: &'b Address,
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl InitializePermanentDelegate<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..33]: delegate (32 bytes, Address)
+        let mut instruction_data = [UNINIT_BYTE; 33];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[35]);
+        // Set delegate as Address at offset [1..33]
+        write_bytes(&mut instruction_data[1..], self.delegate.as_array());
+
+        let instruction = InstructionView {
+            program_id: self.token_program,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 33) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
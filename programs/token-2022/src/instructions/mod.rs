@@ -1,3 +1,4 @@
+mod amount_to_ui_amount;
 mod approve;
 mod approve_checked;
 mod burn;
@@ -5,22 +6,31 @@ mod burn_checked;
 mod close_account;
 mod extensions;
 mod freeze_account;
+mod get_account_data_size;
 mod initialize_account;
 mod initialize_account_2;
 mod initialize_account_3;
+mod initialize_immutable_owner;
 mod initialize_mint;
 mod initialize_mint_2;
+mod initialize_mint_close_authority;
 mod initialize_multisig;
 mod initialize_multisig_2;
+mod initialize_non_transferable_mint;
+mod initialize_permanent_delegate;
 mod mint_to;
 mod mint_to_checked;
+mod reallocate;
 mod revoke;
 mod set_authority;
 mod sync_native;
 mod thaw_account;
 mod transfer;
 mod transfer_checked;
+mod ui_amount_to_amount;
+mod withdraw_excess_lamports;
 
+pub use amount_to_ui_amount::*;
 pub use approve::*;
 pub use approve_checked::*;
 pub use burn::*;
@@ -28,18 +38,26 @@ pub use burn_checked::*;
 pub use close_account::*;
 pub use extensions::*;
 pub use freeze_account::*;
+pub use get_account_data_size::*;
 pub use initialize_account::*;
 pub use initialize_account_2::*;
 pub use initialize_account_3::*;
+pub use initialize_immutable_owner::*;
 pub use initialize_mint::*;
 pub use initialize_mint_2::*;
+pub use initialize_mint_close_authority::*;
 pub use initialize_multisig::*;
 pub use initialize_multisig_2::*;
+pub use initialize_non_transferable_mint::*;
+pub use initialize_permanent_delegate::*;
 pub use mint_to::*;
 pub use mint_to_checked::*;
+pub use reallocate::*;
 pub use revoke::*;
 pub use set_authority::*;
 pub use sync_native::*;
 pub use thaw_account::*;
 pub use transfer::*;
 pub use transfer_checked::*;
+pub use ui_amount_to_amount::*;
+pub use withdraw_excess_lamports::*;
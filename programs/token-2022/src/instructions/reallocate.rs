@@ -0,0 +1,166 @@
+use {
+    crate::{instructions::MAX_MULTISIG_SIGNERS, state::ExtensionType, write_bytes, UNINIT_BYTE},
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+/// Maximum number of extension types in a `Reallocate` or
+/// `GetAccountDataSize` instruction.
+pub(crate) const MAX_EXTENSION_TYPES: usize = ExtensionType::PausableAccount as usize + 1;
+
+/// Check to see if a token account is large enough for a list of
+/// extension types, and if not, use reallocation to increase the data
+/// size.
+///
+/// Expected accounts:
+///
+/// **Single owner**
+/// 0. `[writable]` The account to reallocate.
+/// 1. `[signer, writable]` The payer account to fund reallocation.
+/// 2. `[]` System program for reallocation funding.
+/// 3. `[signer]` The account's owner.
+///
+/// **Multisignature owner**
+/// 0. `[writable]` The account to reallocate.
+/// 1. `[signer, writable]` The payer account to fund reallocation.
+/// 2. `[]` System program for reallocation funding.
+/// 3. `[]` The account's multisignature owner/delegate.
+/// 4. `..4+M` `[signer]` M signer accounts.
+pub struct Reallocate<'a, 'b, 'c> {
+    /// Token Account.
+    pub account: &'a AccountView,
+    /// Payer Account.
+    pub payer: &'a AccountView,
+    /// System Program.
+    pub system_program: &'a AccountView,
+    /// Owner Account (single or multisig).
+    pub owner: &'a AccountView,
+    /// Signer accounts if the owner is a multisig.
+    pub signers: &'c [&'a AccountView],
+    /// New extension types to include in the reallocated account.
+    pub extension_types: &'c [ExtensionType],
+    /// Token Program (Token-2022).
+    pub token_program: &'b Address,
+}
+
+impl Reallocate<'_, '_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let &Self {
+            account,
+            payer,
+            system_program,
+            owner,
+            signers: multisig_accounts,
+            extension_types,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS
+            || extension_types.len() > MAX_EXTENSION_TYPES
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 3 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(account.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::writable_signer(payer.address()));
+            instruction_accounts
+                .get_unchecked_mut(2)
+                .write(InstructionAccount::readonly(system_program.address()));
+            instruction_accounts
+                .get_unchecked_mut(3)
+                .write(InstructionAccount::new(
+                    owner.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+        }
+
+        for (instruction_account, signer) in instruction_accounts[4..]
+            .iter_mut()
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..1+2N]: extension types (2 bytes each, u16)
+        let mut instruction_data = [UNINIT_BYTE; 1 + 2 * MAX_EXTENSION_TYPES];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[29]);
+        // Set extension types as u16 at offset [1..1+2N]
+        for (data, extension_type) in instruction_data[1..]
+            .chunks_exact_mut(2)
+            .zip(extension_types.iter())
+        {
+            write_bytes(data, &(*extension_type as u16).to_le_bytes());
+        }
+
+        let num_accounts = 4 + multisig_accounts.len();
+
+        let instruction = InstructionView {
+            program_id: token_program,
+            data: unsafe {
+                slice::from_raw_parts(
+                    instruction_data.as_ptr() as _,
+                    1 + 2 * extension_types.len(),
+                )
+            },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
+        };
+
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 4 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to 4 + MAX_MULTISIG_SIGNERS
+            // - Index 0 to 3 are always present
+            account_views.get_unchecked_mut(0).write(account);
+            account_views.get_unchecked_mut(1).write(payer);
+            account_views.get_unchecked_mut(2).write(system_program);
+            account_views.get_unchecked_mut(3).write(owner);
+        }
+
+        // Fill signer accounts
+        for (account_view, signer) in account_views[4..].iter_mut().zip(multisig_accounts.iter()) {
+            account_view.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 4 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
+    }
+}
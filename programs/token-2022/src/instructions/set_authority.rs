@@ -1,34 +1,73 @@
-use core::slice::from_raw_parts;
-
-use solana_account_view::AccountView;
-use solana_address::Address;
-use solana_instruction_view::{
-    cpi::{invoke_signed, Signer},
-    InstructionAccount, InstructionView,
+use {
+    crate::{instructions::MAX_MULTISIG_SIGNERS, write_bytes, UNINIT_BYTE},
+    core::{mem::MaybeUninit, slice},
+    solana_account_view::AccountView,
+    solana_address::Address,
+    solana_instruction_view::{
+        cpi::{invoke_signed_with_bounds, Signer},
+        InstructionAccount, InstructionView,
+    },
+    solana_program_error::{ProgramError, ProgramResult},
 };
-use solana_program_error::ProgramResult;
-
-use crate::{write_bytes, UNINIT_BYTE};
 
 #[repr(u8)]
 #[derive(Clone, Copy)]
 pub enum AuthorityType {
+    /// Authority to mint new tokens.
     MintTokens = 0,
+    /// Authority to freeze any account associated with the mint.
     FreezeAccount = 1,
+    /// Owner of a given token account.
     AccountOwner = 2,
+    /// Authority to close a token account.
     CloseAccount = 3,
+    /// Authority to set the transfer fee.
+    TransferFeeConfig = 4,
+    /// Authority to withdraw withheld tokens from a mint.
+    WithheldWithdraw = 5,
+    /// Authority to close a mint account.
+    CloseMint = 6,
+    /// Authority to set the interest rate.
+    InterestRate = 7,
+    /// Authority to transfer or burn any tokens of the mint.
+    PermanentDelegate = 8,
+    /// Authority to update the confidential transfer mint and approve
+    /// accounts for confidential transfers.
+    ConfidentialTransferMint = 9,
+    /// Authority to set the transfer hook program id.
+    TransferHookProgramId = 10,
+    /// Authority to set the withdraw withheld authority ElGamal key.
+    ConfidentialTransferFeeConfig = 11,
+    /// Authority to set the metadata address.
+    MetadataPointer = 12,
+    /// Authority to set the group address.
+    GroupPointer = 13,
+    /// Authority to set the group member address.
+    GroupMemberPointer = 14,
+    /// Authority to set the UI amount scale.
+    ScaledUiAmount = 15,
+    /// Authority to pause or resume minting, transferring and burning.
+    Pause = 16,
 }
 
 /// Sets a new authority of a mint or account.
 ///
 /// ### Accounts:
+///   * Single authority
 ///   0. `[WRITE]` The mint or account to change the authority of.
 ///   1. `[SIGNER]` The current authority of the mint or account.
-pub struct SetAuthority<'a, 'b> {
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The mint or account to change the authority of.
+///   1. `[]` The mint's or account's current multisignature authority.
+///   2. ..2+M `[SIGNER]` M signer accounts.
+pub struct SetAuthority<'a, 'b, 'c> {
     /// Account (Mint or Token)
     pub account: &'a AccountView,
-    /// Authority of the Account.
+    /// Authority of the Account (single or multisig).
     pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'c [&'a AccountView],
     /// The type of authority to update.
     pub authority_type: AuthorityType,
     /// The new authority
@@ -37,7 +76,7 @@ pub struct SetAuthority<'a, 'b> {
     pub token_program: &'b Address,
 }
 
-impl SetAuthority<'_, '_> {
+impl SetAuthority<'_, '_, '_> {
     #[inline(always)]
     pub fn invoke(&self) -> ProgramResult {
         self.invoke_signed(&[])
@@ -45,11 +84,46 @@ impl SetAuthority<'_, '_> {
 
     #[inline(always)]
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
-        // Instruction accounts
-        let instruction_accounts: [InstructionAccount; 2] = [
-            InstructionAccount::writable(self.account.address()),
-            InstructionAccount::readonly_signer(self.authority.address()),
-        ];
+        let &Self {
+            account,
+            authority,
+            signers: multisig_accounts,
+            authority_type,
+            new_authority,
+            token_program,
+        } = self;
+
+        if multisig_accounts.len() > MAX_MULTISIG_SIGNERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        const UNINIT_INSTRUCTION_ACCOUNTS: MaybeUninit<InstructionAccount> =
+            MaybeUninit::<InstructionAccount>::uninit();
+        let mut instruction_accounts = [UNINIT_INSTRUCTION_ACCOUNTS; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `instruction_accounts` is sized to 2 + MAX_MULTISIG_SIGNERS
+            // - Index 0 and 1 are always present
+            instruction_accounts
+                .get_unchecked_mut(0)
+                .write(InstructionAccount::writable(account.address()));
+            instruction_accounts
+                .get_unchecked_mut(1)
+                .write(InstructionAccount::new(
+                    authority.address(),
+                    false,
+                    multisig_accounts.is_empty(),
+                ));
+        }
+
+        // Fill signer accounts
+        for (instruction_account, signer) in instruction_accounts[2..]
+            .iter_mut()
+            .zip(multisig_accounts.iter())
+        {
+            instruction_account.write(InstructionAccount::readonly_signer(signer.address()));
+        }
 
         // instruction data
         // -  [0]: instruction discriminator (1 byte, u8)
@@ -62,9 +136,9 @@ impl SetAuthority<'_, '_> {
         // Set discriminator as u8 at offset [0]
         write_bytes(&mut instruction_data, &[6]);
         // Set authority_type as u8 at offset [1]
-        write_bytes(&mut instruction_data[1..2], &[self.authority_type as u8]);
+        write_bytes(&mut instruction_data[1..2], &[authority_type as u8]);
 
-        if let Some(new_authority) = self.new_authority {
+        if let Some(new_authority) = new_authority {
             // Set new_authority as [u8; 32] at offset [2..35]
             write_bytes(&mut instruction_data[2..3], &[1]);
             write_bytes(&mut instruction_data[3..], new_authority.as_array());
@@ -74,12 +148,39 @@ impl SetAuthority<'_, '_> {
             length = 3;
         }
 
+        let num_accounts = 2 + multisig_accounts.len();
+
         let instruction = InstructionView {
-            program_id: self.token_program,
-            accounts: &instruction_accounts,
-            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, length) },
+            program_id: token_program,
+            data: unsafe { slice::from_raw_parts(instruction_data.as_ptr() as _, length) },
+            accounts: unsafe {
+                slice::from_raw_parts(instruction_accounts.as_ptr() as _, num_accounts)
+            },
         };
 
-        invoke_signed(&instruction, &[self.account, self.authority], signers)
+        // Account view array
+        const UNINIT_ACCOUNT_VIEWS: MaybeUninit<&AccountView> = MaybeUninit::uninit();
+        let mut account_views = [UNINIT_ACCOUNT_VIEWS; 2 + MAX_MULTISIG_SIGNERS];
+
+        unsafe {
+            // SAFETY:
+            // - `account_views` is sized to 2 + MAX_MULTISIG_SIGNERS
+            // - Index 0 and 1 are always present
+            account_views.get_unchecked_mut(0).write(account);
+            account_views.get_unchecked_mut(1).write(authority);
+        }
+
+        // Fill signer accounts
+        for (account_view, signer) in account_views[2..].iter_mut().zip(multisig_accounts.iter()) {
+            account_view.write(signer);
+        }
+
+        invoke_signed_with_bounds::<{ 2 + MAX_MULTISIG_SIGNERS }>(
+            &instruction,
+            unsafe {
+                slice::from_raw_parts(account_views.as_ptr() as *const &AccountView, num_accounts)
+            },
+            signers,
+        )
     }
 }
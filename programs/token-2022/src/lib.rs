@@ -2,6 +2,7 @@
 
 pub mod instructions;
 pub mod state;
+pub mod transfer_hook;
 
 use core::mem::MaybeUninit;
 
@@ -0,0 +1,56 @@
+use super::{
+    AccountType, Extension, ExtensionType, PodAeCiphertext, PodElGamalCiphertext, PodElGamalPubkey,
+};
+
+/// Confidential mint and burn extension data for mints.
+#[repr(C)]
+pub struct ConfidentialMintBurn {
+    /// The confidential supply of the mint (encrypted by `supply_elgamal_pubkey`).
+    confidential_supply: PodElGamalCiphertext,
+
+    /// The decryptable confidential supply of the mint.
+    decryptable_supply: PodAeCiphertext,
+
+    /// The ElGamal public key used to encrypt the confidential supply.
+    supply_elgamal_pubkey: PodElGamalPubkey,
+
+    /// The amount of burn amounts not yet aggregated into the confidential supply.
+    pending_burn: PodElGamalCiphertext,
+}
+
+impl Extension for ConfidentialMintBurn {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialMintBurn;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ConfidentialMintBurn)
+    }
+}
+
+impl ConfidentialMintBurn {
+    /// Confidential supply of the mint, encrypted under the supply ElGamal key.
+    #[inline(always)]
+    pub fn confidential_supply(&self) -> &PodElGamalCiphertext {
+        &self.confidential_supply
+    }
+
+    /// Decryptable supply of the mint.
+    #[inline(always)]
+    pub fn decryptable_supply(&self) -> &PodAeCiphertext {
+        &self.decryptable_supply
+    }
+
+    /// ElGamal public key used to encrypt the confidential supply.
+    #[inline(always)]
+    pub fn supply_elgamal_pubkey(&self) -> &PodElGamalPubkey {
+        &self.supply_elgamal_pubkey
+    }
+
+    /// Amount burned since the last `ApplyPendingBurn`.
+    #[inline(always)]
+    pub fn pending_burn(&self) -> &PodElGamalCiphertext {
+        &self.pending_burn
+    }
+}
@@ -0,0 +1,84 @@
+use solana_address::Address;
+
+use super::{
+    optional_address, AccountType, Extension, ExtensionType, PodElGamalCiphertext, PodElGamalPubkey,
+};
+
+/// Confidential transfer fee extension data for mints.
+#[repr(C)]
+pub struct ConfidentialTransferFeeConfig {
+    /// Optional authority to set the withdraw withheld authority ElGamal key.
+    authority: Address,
+
+    /// Withheld fees from accounts must be encrypted with this ElGamal key.
+    withdraw_withheld_authority_elgamal_pubkey: PodElGamalPubkey,
+
+    /// If `false`, the harvest of withheld tokens to mint is rejected.
+    harvest_to_mint_enabled: u8,
+
+    /// Withheld confidential transfer fee tokens that have been moved to the
+    /// mint for withdrawal.
+    withheld_amount: PodElGamalCiphertext,
+}
+
+impl Extension for ConfidentialTransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferFeeConfig;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ConfidentialTransferFeeConfig)
+    }
+}
+
+impl ConfidentialTransferFeeConfig {
+    /// Optional authority that can set the withdraw withheld authority ElGamal key.
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    /// ElGamal public key used to encrypt withheld fees.
+    #[inline(always)]
+    pub fn withdraw_withheld_authority_elgamal_pubkey(&self) -> &PodElGamalPubkey {
+        &self.withdraw_withheld_authority_elgamal_pubkey
+    }
+
+    /// Indicates whether harvesting withheld fees to the mint is enabled.
+    #[inline(always)]
+    pub fn harvest_to_mint_enabled(&self) -> bool {
+        self.harvest_to_mint_enabled != 0
+    }
+
+    /// Withheld confidential fees that have been harvested to the mint.
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> &PodElGamalCiphertext {
+        &self.withheld_amount
+    }
+}
+
+/// Confidential transfer fee extension data for token accounts.
+#[repr(C)]
+pub struct ConfidentialTransferFeeAmount {
+    /// Amount withheld during confidential transfers, to be harvested to the mint.
+    withheld_amount: PodElGamalCiphertext,
+}
+
+impl Extension for ConfidentialTransferFeeAmount {
+    const TYPE: ExtensionType = ExtensionType::ConfidentialTransferFeeAmount;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Account;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ConfidentialTransferFeeAmount)
+    }
+}
+
+impl ConfidentialTransferFeeAmount {
+    /// Withheld confidential fees to be harvested to the mint.
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> &PodElGamalCiphertext {
+        &self.withheld_amount
+    }
+}
@@ -0,0 +1,28 @@
+use super::{AccountType, Extension, ExtensionType};
+
+/// CPI Guard extension data for token accounts.
+#[repr(C)]
+pub struct CpiGuard {
+    /// Lock privileged token operations from happening via CPI.
+    lock_cpi: u8,
+}
+
+impl Extension for CpiGuard {
+    const TYPE: ExtensionType = ExtensionType::CpiGuard;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Account;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const CpiGuard)
+    }
+}
+
+impl CpiGuard {
+    /// Indicates whether privileged token operations are locked from
+    /// happening via CPI.
+    #[inline(always)]
+    pub fn lock_cpi(&self) -> bool {
+        self.lock_cpi != 0
+    }
+}
@@ -0,0 +1,38 @@
+use solana_program_error::ProgramError;
+
+use super::{AccountType, Extension, ExtensionType};
+
+use crate::state::AccountState;
+
+/// Default account state extension data for mints.
+#[repr(C)]
+pub struct DefaultAccountState {
+    /// Default state for new accounts.
+    state: u8,
+}
+
+impl Extension for DefaultAccountState {
+    const TYPE: ExtensionType = ExtensionType::DefaultAccountState;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const DefaultAccountState)
+    }
+}
+
+impl DefaultAccountState {
+    /// The state new token accounts of the mint are initialized with.
+    ///
+    /// An error is returned if the stored state is not a valid `AccountState`.
+    #[inline(always)]
+    pub fn state(&self) -> Result<AccountState, ProgramError> {
+        match self.state {
+            0 => Ok(AccountState::Uninitialized),
+            1 => Ok(AccountState::Initialized),
+            2 => Ok(AccountState::Frozen),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Group member pointer extension data for mints.
+#[repr(C)]
+pub struct GroupMemberPointer {
+    /// Authority that can set the member address.
+    authority: Address,
+
+    /// Account address that holds the member configurations.
+    member_address: Address,
+}
+
+impl Extension for GroupMemberPointer {
+    const TYPE: ExtensionType = ExtensionType::GroupMemberPointer;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const GroupMemberPointer)
+    }
+}
+
+impl GroupMemberPointer {
+    /// Optional authority that can set the member address.
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    /// Optional address of the account holding the member configuration.
+    pub fn member_address(&self) -> Option<&Address> {
+        optional_address(&self.member_address)
+    }
+}
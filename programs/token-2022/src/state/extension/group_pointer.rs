@@ -0,0 +1,36 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Group pointer extension data for mints.
+#[repr(C)]
+pub struct GroupPointer {
+    /// Authority that can set the group address.
+    authority: Address,
+
+    /// Account address that holds the group configurations.
+    group_address: Address,
+}
+
+impl Extension for GroupPointer {
+    const TYPE: ExtensionType = ExtensionType::GroupPointer;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const GroupPointer)
+    }
+}
+
+impl GroupPointer {
+    /// Optional authority that can set the group address.
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    /// Optional address of the account holding the group configuration.
+    pub fn group_address(&self) -> Option<&Address> {
+        optional_address(&self.group_address)
+    }
+}
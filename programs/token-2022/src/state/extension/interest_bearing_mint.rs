@@ -0,0 +1,408 @@
+use pinocchio::sysvars::clock::Clock;
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+use super::{optional_address, powi, write_ui_amount, AccountType, Extension, ExtensionType};
+
+/// Number of seconds in a year, using the average length of a year.
+const SECONDS_PER_YEAR: f64 = 60.0 * 60.0 * 24.0 * 365.24;
+
+/// The rates are expressed in basis points.
+const ONE_IN_BASIS_POINTS: f64 = 10_000.;
+
+/// Interest-bearing extension data for mints.
+///
+/// Tokens accrue interest at an annual rate expressed in basis points, compounded
+/// continuously.
+#[repr(C)]
+pub struct InterestBearingConfig {
+    /// Authority that can set the interest rate and authority.
+    rate_authority: Address,
+
+    /// Timestamp of initialization, from which to base interest calculations.
+    initialization_timestamp: [u8; 8],
+
+    /// Average rate from initialization until the last time it was updated.
+    pre_update_average_rate: [u8; 2],
+
+    /// Timestamp of the last update, used to calculate the total amount accrued.
+    last_update_timestamp: [u8; 8],
+
+    /// Current rate, since the last update.
+    current_rate: [u8; 2],
+}
+
+impl Extension for InterestBearingConfig {
+    const TYPE: ExtensionType = ExtensionType::InterestBearingConfig;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const InterestBearingConfig)
+    }
+}
+
+impl InterestBearingConfig {
+    /// Optional authority that can set the interest rate.
+    pub fn rate_authority(&self) -> Option<&Address> {
+        optional_address(&self.rate_authority)
+    }
+
+    /// Timestamp of the initialization of the interest-bearing config.
+    #[inline(always)]
+    pub fn initialization_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.initialization_timestamp)
+    }
+
+    /// Average rate from initialization until the last rate update, in basis points.
+    #[inline(always)]
+    pub fn pre_update_average_rate(&self) -> i16 {
+        i16::from_le_bytes(self.pre_update_average_rate)
+    }
+
+    /// Timestamp of the last rate update.
+    #[inline(always)]
+    pub fn last_update_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.last_update_timestamp)
+    }
+
+    /// Current rate, in basis points, since the last update.
+    #[inline(always)]
+    pub fn current_rate(&self) -> i16 {
+        i16::from_le_bytes(self.current_rate)
+    }
+
+    /// Return the scale to convert an amount of tokens to its interest-adjusted
+    /// UI amount at the time of the given clock.
+    pub fn total_scale(&self, decimals: u8, clock: &Clock) -> Option<f64> {
+        let pre_update_exp = Self::compounded_exp(
+            self.pre_update_average_rate(),
+            self.last_update_timestamp()
+                .checked_sub(self.initialization_timestamp())?,
+        )?;
+        let post_update_exp = Self::compounded_exp(
+            self.current_rate(),
+            clock
+                .unix_timestamp
+                .checked_sub(self.last_update_timestamp())?,
+        )?;
+
+        Some(pre_update_exp * post_update_exp / powi(10., decimals as i32))
+    }
+
+    /// Convert a raw amount of tokens to its interest-adjusted UI amount.
+    ///
+    /// The UI amount is written to `buffer` with trailing zeros trimmed, matching
+    /// the representation returned by Token-2022. Returns `None` if the computation
+    /// overflows or `buffer` is too small.
+    pub fn amount_to_ui_amount<'a>(
+        &self,
+        amount: u64,
+        decimals: u8,
+        clock: &Clock,
+        buffer: &'a mut [u8],
+    ) -> Option<&'a str> {
+        let ui_amount = (amount as f64) * self.total_scale(decimals, clock)?;
+        write_ui_amount(ui_amount, decimals, buffer)
+    }
+
+    /// Convert an interest-adjusted UI amount to its raw amount of tokens.
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        clock: &Clock,
+    ) -> Result<u64, ProgramError> {
+        let ui_amount = ui_amount
+            .parse::<f64>()
+            .map_err(|_| ProgramError::InvalidArgument)?;
+        let amount = ui_amount
+            / self
+                .total_scale(decimals, clock)
+                .ok_or(ProgramError::InvalidArgument)?;
+
+        if amount > (u64::MAX as f64) || amount < (u64::MIN as f64) || amount.is_nan() {
+            Err(ProgramError::InvalidArgument)
+        } else {
+            // Rounding must happen after the division, otherwise large amounts
+            // would overflow to infinity.
+            Ok(libm::round(amount) as u64)
+        }
+    }
+
+    /// Return `e^(rate * timespan)`, with the rate expressed in basis points per
+    /// year and the timespan in seconds.
+    #[inline(always)]
+    fn compounded_exp(rate: i16, timespan: i64) -> Option<f64> {
+        let numerator = (rate as i128).checked_mul(timespan as i128)? as f64;
+        let exponent = numerator / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS;
+        Some(libm::exp(exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INT_SECONDS_PER_YEAR: i64 = 6 * 6 * 24 * 36524;
+
+    const TEST_DECIMALS: u8 = 2;
+
+    const ONE: u64 = 1_000_000_000_000_000_000;
+
+    /// Return a config initialized at `0` whose rate was updated after a year.
+    fn config(pre_update_average_rate: i16, current_rate: i16) -> InterestBearingConfig {
+        InterestBearingConfig {
+            rate_authority: Address::new_from_array([0; 32]),
+            initialization_timestamp: 0i64.to_le_bytes(),
+            pre_update_average_rate: pre_update_average_rate.to_le_bytes(),
+            last_update_timestamp: INT_SECONDS_PER_YEAR.to_le_bytes(),
+            current_rate: current_rate.to_le_bytes(),
+        }
+    }
+
+    fn clock(unix_timestamp: i64) -> Clock {
+        Clock {
+            slot: 0,
+            epoch_start_timestamp: 0,
+            epoch: 0,
+            leader_schedule_epoch: 0,
+            unix_timestamp,
+        }
+    }
+
+    fn assert_ui_amount(
+        config: &InterestBearingConfig,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+        expected: &str,
+    ) {
+        let mut buffer = [0; 512];
+
+        assert_eq!(
+            config.amount_to_ui_amount(amount, decimals, &clock(unix_timestamp), &mut buffer),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn seconds_per_year() {
+        assert_eq!(SECONDS_PER_YEAR, 31_556_736.);
+        assert_eq!(INT_SECONDS_PER_YEAR, 31_556_736);
+    }
+
+    #[test]
+    fn specific_amount_to_ui_amount() {
+        // Constant 5%, 1 year gives a total of exp(0.05) = 1.0512710963760241.
+        let config = config(500, 500);
+        assert_ui_amount(
+            &config,
+            ONE,
+            18,
+            INT_SECONDS_PER_YEAR,
+            "1.051271096376024117",
+        );
+        // With 1 decimal place.
+        assert_ui_amount(
+            &config,
+            ONE,
+            19,
+            INT_SECONDS_PER_YEAR,
+            "0.1051271096376024117",
+        );
+        // With 10 decimal places, different digits at the end.
+        assert_ui_amount(
+            &config,
+            ONE,
+            28,
+            INT_SECONDS_PER_YEAR,
+            "0.0000000001051271096376024175",
+        );
+        // Huge amount with 10 decimal places.
+        assert_ui_amount(
+            &config,
+            10_000_000_000,
+            10,
+            INT_SECONDS_PER_YEAR,
+            "1.0512710964",
+        );
+
+        // Negative, 1 year at -5% gives a total of exp(-0.05) = 0.951229424500714.
+        let config = self::config(-500, -500);
+        assert_ui_amount(
+            &config,
+            ONE,
+            18,
+            INT_SECONDS_PER_YEAR,
+            "0.951229424500713905",
+        );
+
+        // Net out, 1 year at -5% and 1 year at 5% gives a total of 1.
+        let config = self::config(-500, 500);
+        assert_ui_amount(&config, 1, 0, INT_SECONDS_PER_YEAR * 2, "1");
+
+        // Huge values.
+        let config = self::config(500, 500);
+        assert_ui_amount(
+            &config,
+            u64::MAX,
+            0,
+            INT_SECONDS_PER_YEAR * 2,
+            "20386805083448098816",
+        );
+        // There's an underflow risk, but it works!
+        assert_ui_amount(
+            &config,
+            u64::MAX,
+            0,
+            INT_SECONDS_PER_YEAR * 10_000,
+            "258917064265813826192025834755112557504850551118283225815045099303279643822914042296793377611277551888244755303462190670431480816358154467489350925148558569427069926786360814068189956495940285398273555561779717914539956777398245259214848",
+        );
+
+        // Buffer too small.
+        let mut buffer = [0; 4];
+        assert_eq!(
+            config.amount_to_ui_amount(u64::MAX, 0, &clock(INT_SECONDS_PER_YEAR), &mut buffer),
+            None
+        );
+    }
+
+    #[test]
+    fn specific_ui_amount_to_amount() {
+        // Constant 5%, 1 year gives a total of exp(0.05) = 1.0512710963760241.
+        let config = config(500, 500);
+        let clock = clock(INT_SECONDS_PER_YEAR);
+
+        assert_eq!(
+            config.try_ui_amount_into_amount("1.0512710963760241", 0, &clock),
+            Ok(1)
+        );
+        // With 1 decimal place.
+        assert_eq!(
+            config.try_ui_amount_into_amount("0.10512710963760241", 1, &clock),
+            Ok(1)
+        );
+        // With 10 decimal places.
+        assert_eq!(
+            config.try_ui_amount_into_amount("0.00000000010512710963760242", 10, &clock),
+            Ok(1)
+        );
+        // Huge amount with 10 decimal places.
+        assert_eq!(
+            config.try_ui_amount_into_amount("1.0512710963760241", 10, &clock),
+            Ok(10_000_000_000)
+        );
+
+        // Negative, 1 year at -5% gives a total of exp(-0.05) = 0.951229424500714.
+        let config = self::config(-500, -500);
+        assert_eq!(
+            config.try_ui_amount_into_amount("0.951229424500714", 0, &clock),
+            Ok(1)
+        );
+
+        // Net out, 1 year at -5% and 1 year at 5% gives a total of 1.
+        let config = self::config(-500, 500);
+        assert_eq!(
+            config.try_ui_amount_into_amount("1", 0, &self::clock(INT_SECONDS_PER_YEAR * 2)),
+            Ok(1)
+        );
+
+        // Huge values.
+        let config = self::config(500, 500);
+        assert_eq!(
+            config.try_ui_amount_into_amount(
+                "20386805083448100000",
+                0,
+                &self::clock(INT_SECONDS_PER_YEAR * 2)
+            ),
+            Ok(u64::MAX)
+        );
+        let clock = self::clock(INT_SECONDS_PER_YEAR * 10_000);
+        assert_eq!(
+            config.try_ui_amount_into_amount(
+                "258917064265813830000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                0,
+                &clock
+            ),
+            Ok(u64::MAX)
+        );
+        // Scientific notation "e".
+        assert_eq!(
+            config.try_ui_amount_into_amount("2.5891706426581383e236", 0, &clock),
+            Ok(u64::MAX)
+        );
+        // Scientific notation "E".
+        assert_eq!(
+            config.try_ui_amount_into_amount("2.5891706426581383E236", 0, &clock),
+            Ok(u64::MAX)
+        );
+
+        // Overflow u64 fail.
+        let clock = self::clock(INT_SECONDS_PER_YEAR);
+        assert_eq!(
+            config.try_ui_amount_into_amount("20386805083448200001", 0, &clock),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        for fail_ui_amount in ["-0.0000000000000000000001", "inf", "-inf", "NaN"] {
+            assert_eq!(
+                config.try_ui_amount_into_amount(fail_ui_amount, 0, &clock),
+                Err(ProgramError::InvalidArgument)
+            );
+        }
+    }
+
+    #[test]
+    fn specific_amount_to_ui_amount_no_interest() {
+        let config = config(0, 0);
+
+        for (amount, expected) in [(23, "0.23"), (110, "1.1"), (4200, "42"), (0, "0")] {
+            assert_ui_amount(
+                &config,
+                amount,
+                TEST_DECIMALS,
+                INT_SECONDS_PER_YEAR,
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn specific_ui_amount_to_amount_no_interest() {
+        let config = config(0, 0);
+        let clock = clock(INT_SECONDS_PER_YEAR);
+
+        for (ui_amount, expected) in [
+            ("0.23", 23),
+            ("0.20", 20),
+            ("0.2000", 20),
+            (".2", 20),
+            ("1.1", 110),
+            ("1.10", 110),
+            ("42", 4200),
+            ("42.", 4200),
+            ("0", 0),
+        ] {
+            assert_eq!(
+                config.try_ui_amount_into_amount(ui_amount, TEST_DECIMALS, &clock),
+                Ok(expected)
+            );
+        }
+
+        // This is invalid with normal mints, but rounding for this mint makes it ok.
+        assert_eq!(
+            config.try_ui_amount_into_amount("0.111", TEST_DECIMALS, &clock),
+            Ok(11)
+        );
+
+        // Fail if invalid ui_amount passed in.
+        for ui_amount in ["", ".", "0.t"] {
+            assert_eq!(
+                config.try_ui_amount_into_amount(ui_amount, TEST_DECIMALS, &clock),
+                Err(ProgramError::InvalidArgument)
+            );
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use super::{AccountType, Extension, ExtensionType};
+
+/// Memo transfer extension data for token accounts.
+#[repr(C)]
+pub struct MemoTransfer {
+    /// Require transfers into this account to be accompanied by a memo.
+    require_incoming_transfer_memos: u8,
+}
+
+impl Extension for MemoTransfer {
+    const TYPE: ExtensionType = ExtensionType::MemoTransfer;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Account;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const MemoTransfer)
+    }
+}
+
+impl MemoTransfer {
+    /// Indicates whether transfers into the account must be accompanied by
+    /// a memo.
+    #[inline(always)]
+    pub fn require_incoming_transfer_memos(&self) -> bool {
+        self.require_incoming_transfer_memos != 0
+    }
+}
@@ -0,0 +1,36 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Metadata pointer extension data for mints.
+#[repr(C)]
+pub struct MetadataPointer {
+    /// Authority that can set the metadata address.
+    authority: Address,
+
+    /// Account address that holds the metadata.
+    metadata_address: Address,
+}
+
+impl Extension for MetadataPointer {
+    const TYPE: ExtensionType = ExtensionType::MetadataPointer;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const MetadataPointer)
+    }
+}
+
+impl MetadataPointer {
+    /// Optional authority that can set the metadata address.
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    /// Optional address of the account holding the metadata.
+    pub fn metadata_address(&self) -> Option<&Address> {
+        optional_address(&self.metadata_address)
+    }
+}
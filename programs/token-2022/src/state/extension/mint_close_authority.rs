@@ -0,0 +1,28 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Close authority extension data for mints.
+#[repr(C)]
+pub struct MintCloseAuthority {
+    /// Optional authority to close the mint.
+    close_authority: Address,
+}
+
+impl Extension for MintCloseAuthority {
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const MintCloseAuthority)
+    }
+}
+
+impl MintCloseAuthority {
+    /// Optional authority that can close the mint.
+    pub fn close_authority(&self) -> Option<&Address> {
+        optional_address(&self.close_authority)
+    }
+}
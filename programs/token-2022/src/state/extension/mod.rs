@@ -0,0 +1,666 @@
+mod confidential_mint_burn;
+mod confidential_transfer_fee;
+mod cpi_guard;
+mod default_account_state;
+mod group_member_pointer;
+mod group_pointer;
+mod interest_bearing_mint;
+mod memo_transfer;
+mod metadata_pointer;
+mod mint_close_authority;
+mod pausable;
+mod permanent_delegate;
+mod pod;
+mod scaled_ui_amount;
+mod token_group;
+mod token_metadata;
+mod transfer_fee;
+mod transfer_hook;
+
+pub use confidential_mint_burn::*;
+pub use confidential_transfer_fee::*;
+pub use cpi_guard::*;
+pub use default_account_state::*;
+pub use group_member_pointer::*;
+pub use group_pointer::*;
+pub use interest_bearing_mint::*;
+pub use memo_transfer::*;
+pub use metadata_pointer::*;
+pub use mint_close_authority::*;
+pub use pausable::*;
+pub use permanent_delegate::*;
+pub use pod::*;
+pub use scaled_ui_amount::*;
+pub use token_group::*;
+pub use token_metadata::*;
+pub use transfer_fee::*;
+pub use transfer_hook::*;
+
+use core::fmt::Write;
+
+use solana_account_view::{AccountView, Ref};
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+use super::{Mint, Multisig, TokenAccount};
+
+use crate::ID;
+
+/// Offset of the `AccountType` discriminator on accounts with extensions.
+///
+/// Mints with extensions are padded up to the length of a token account, so
+/// the discriminator is at the same offset for both account types.
+pub const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::BASE_LEN;
+
+/// Length of the type and length header of each TLV entry.
+const TLV_HEADER_LEN: usize = 4;
+
+/// Account type stored after the base state of accounts with extensions.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AccountType {
+    /// Marker for 0 data.
+    Uninitialized,
+
+    /// Mint account with additional extensions.
+    Mint,
+
+    /// Token holding account with additional extensions.
+    Account,
+}
+
+impl AccountType {
+    /// Length of the base state of the account type.
+    #[inline(always)]
+    pub const fn base_len(self) -> usize {
+        match self {
+            AccountType::Uninitialized => 0,
+            AccountType::Mint => Mint::BASE_LEN,
+            AccountType::Account => TokenAccount::BASE_LEN,
+        }
+    }
+}
+
+impl TryFrom<u8> for AccountType {
+    type Error = ProgramError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AccountType::Uninitialized),
+            1 => Ok(AccountType::Mint),
+            2 => Ok(AccountType::Account),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Extensions that can be applied to mints or accounts.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtensionType {
+    /// Used as padding if the account size would otherwise be 355, same as a
+    /// multisig.
+    Uninitialized,
+    /// Includes transfer fee rate info and accompanying authorities to
+    /// withdraw and set the fee.
+    TransferFeeConfig,
+    /// Includes withheld transfer fees.
+    TransferFeeAmount,
+    /// Includes an optional mint close authority.
+    MintCloseAuthority,
+    /// Auditor configuration for confidential transfers.
+    ConfidentialTransferMint,
+    /// State for confidential transfers.
+    ConfidentialTransferAccount,
+    /// Specifies the default account state for new accounts.
+    DefaultAccountState,
+    /// Indicates that the account owner authority cannot be changed.
+    ImmutableOwner,
+    /// Require inbound transfers to have memo.
+    MemoTransfer,
+    /// Indicates that the tokens from this mint can't be transferred.
+    NonTransferable,
+    /// Tokens accrue interest over time.
+    InterestBearingConfig,
+    /// Locks privileged token operations from happening via CPI.
+    CpiGuard,
+    /// Includes an optional permanent delegate.
+    PermanentDelegate,
+    /// Indicates that the tokens in this account belong to a non-transferable
+    /// mint.
+    NonTransferableAccount,
+    /// Mint requires a CPI to a program implementing the "transfer hook"
+    /// interface.
+    TransferHook,
+    /// Indicates that the tokens in this account belong to a mint with a
+    /// transfer hook.
+    TransferHookAccount,
+    /// Includes encrypted withheld fees and the encryption public that they
+    /// are encrypted under.
+    ConfidentialTransferFeeConfig,
+    /// Includes confidential withheld transfer fees.
+    ConfidentialTransferFeeAmount,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds metadata.
+    MetadataPointer,
+    /// Mint contains token-metadata.
+    TokenMetadata,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group configurations.
+    GroupPointer,
+    /// Mint contains token group configurations.
+    TokenGroup,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group member configurations.
+    GroupMemberPointer,
+    /// Mint contains token group member configurations.
+    TokenGroupMember,
+    /// Mint allowing the minting and burning of confidential tokens.
+    ConfidentialMintBurn,
+    /// Tokens whose UI amount is scaled by a given amount.
+    ScaledUiAmount,
+    /// Tokens where minting / burning / transferring can be paused.
+    Pausable,
+    /// Indicates that the account belongs to a pausable mint.
+    PausableAccount,
+}
+
+impl TryFrom<u16> for ExtensionType {
+    type Error = ProgramError;
+
+    #[inline]
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        if value <= ExtensionType::PausableAccount as u16 {
+            // SAFETY: `ExtensionType` is `repr(u16)` and its discriminants are
+            // contiguous from `0` to `PausableAccount`.
+            Ok(unsafe { core::mem::transmute::<u16, ExtensionType>(value) })
+        } else {
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
+
+/// Fixed-length extension data stored in the TLV area of a mint or token
+/// account.
+pub trait Extension: Sized {
+    /// The type of the extension.
+    const TYPE: ExtensionType;
+
+    /// The type of account that holds the extension.
+    const ACCOUNT_TYPE: AccountType;
+
+    /// The length of the extension data.
+    const LEN: usize = core::mem::size_of::<Self>();
+
+    /// Return the extension from the given bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` contains a valid representation of the
+    /// extension and has at least `Self::LEN` bytes.
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self;
+
+    /// Return the extension from the given account view.
+    ///
+    /// This method performs owner, account type and TLV validation on `AccountView`,
+    /// safe borrowing the account data. `None` is returned if the account does not
+    /// hold the extension.
+    #[inline]
+    fn from_account_view(account_view: &AccountView) -> Result<Option<Ref<Self>>, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        // The borrow is mapped with an `Option<ProgramError>` error so a missing
+        // extension (`None`) can be told apart from invalid data.
+        match Ref::try_map(account_view.try_borrow()?, |data| {
+            Self::from_account_data(data)?.ok_or(None)
+        }) {
+            Ok(extension) => Ok(Some(extension)),
+            Err((_, None)) => Ok(None),
+            Err((_, Some(error))) => Err(error),
+        }
+    }
+
+    /// Return the extension from the given account view.
+    ///
+    /// This method performs owner, account type and TLV validation on `AccountView`,
+    /// but does not perform the borrow check. `None` is returned if the account does
+    /// not hold the extension.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data).
+    #[inline]
+    unsafe fn from_account_view_unchecked(
+        account_view: &AccountView,
+    ) -> Result<Option<&Self>, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_account_data(account_view.borrow_unchecked())
+    }
+
+    /// Return the extension from the complete data of a mint or token account.
+    ///
+    /// `None` is returned if the account does not hold the extension.
+    #[inline]
+    fn from_account_data(data: &[u8]) -> Result<Option<&Self>, ProgramError> {
+        let Some(value) = find_extension(tlv_data(data, Self::ACCOUNT_TYPE)?, Self::TYPE)? else {
+            return Ok(None);
+        };
+
+        if value.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // SAFETY: `value` has exactly `Self::LEN` bytes.
+        Ok(Some(unsafe { Self::from_bytes_unchecked(value) }))
+    }
+}
+
+/// Return the TLV area of the complete data of a mint or token account.
+///
+/// Accounts without extensions have an empty TLV area. An error is returned
+/// if the data is too short, has the length of a `Multisig` or does not hold
+/// the expected account type.
+#[inline]
+pub fn tlv_data(data: &[u8], account_type: AccountType) -> Result<&[u8], ProgramError> {
+    let base_len = account_type.base_len();
+
+    if account_type == AccountType::Uninitialized || data.len() < base_len {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if data.len() == base_len {
+        return Ok(&[]);
+    }
+
+    if data.len() <= ACCOUNT_TYPE_OFFSET || data.len() == Multisig::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if data[ACCOUNT_TYPE_OFFSET] != account_type as u8 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(&data[ACCOUNT_TYPE_OFFSET + 1..])
+}
+
+/// Return the value bytes of the first TLV entry with the given type.
+///
+/// `None` is returned if the extension is not present. An error is returned if
+/// any entry before it is malformed.
+#[inline]
+pub fn find_extension(
+    tlv_data: &[u8],
+    extension_type: ExtensionType,
+) -> Result<Option<&[u8]>, ProgramError> {
+    for entry in ExtensionIter::new(tlv_data) {
+        let (ty, value) = entry?;
+
+        if ty == extension_type {
+            return Ok(Some(value));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Return an optional address stored as a non-zero address, where the zero
+/// address represents `None`.
+#[inline(always)]
+pub(crate) fn optional_address(address: &Address) -> Option<&Address> {
+    if address.as_array() == &[0; 32] {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+/// Write the UI representation of an amount with `decimals` fractional digits
+/// into `buffer`, trimming trailing zeros and the decimal point as Token-2022 does.
+pub(crate) fn write_ui_amount(ui_amount: f64, decimals: u8, buffer: &mut [u8]) -> Option<&str> {
+    struct BufferWriter<'a> {
+        buffer: &'a mut [u8],
+        len: usize,
+    }
+
+    impl Write for BufferWriter<'_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.buffer
+                .get_mut(self.len..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let mut writer = BufferWriter { buffer, len: 0 };
+    write!(writer, "{:.*}", decimals as usize, ui_amount).ok()?;
+
+    let BufferWriter { buffer, len } = writer;
+    let ui_amount = core::str::from_utf8(&buffer[..len]).ok()?;
+
+    if decimals > 0 {
+        Some(ui_amount.trim_end_matches('0').trim_end_matches('.'))
+    } else {
+        Some(ui_amount)
+    }
+}
+
+/// Raise `base` to an integer power by repeated squaring, with the same rounding
+/// as the `powi` intrinsic used by Token-2022.
+pub(crate) fn powi(mut base: f64, exponent: i32) -> f64 {
+    let mut exponent_left = exponent;
+    let mut result = 1.;
+
+    loop {
+        if exponent_left & 1 != 0 {
+            result *= base;
+        }
+        exponent_left /= 2;
+        if exponent_left == 0 {
+            break;
+        }
+        base *= base;
+    }
+
+    if exponent < 0 {
+        1. / result
+    } else {
+        result
+    }
+}
+
+/// Zero-copy iterator over the TLV entries of a mint or token account.
+///
+/// Each item is the type and value bytes of an extension. Entries with a type
+/// unknown to this crate are skipped. The iteration stops at the first
+/// `Uninitialized` entry or when fewer bytes than a TLV header remain and they
+/// are all zero (padding). An error is returned for an entry whose value
+/// overflows the data or for non-zero trailing bytes, after which the iteration
+/// stops.
+pub struct ExtensionIter<'a> {
+    /// The TLV area of the account.
+    tlv_data: &'a [u8],
+
+    /// Offset of the next entry.
+    offset: usize,
+}
+
+impl<'a> ExtensionIter<'a> {
+    /// Create an iterator over the given TLV area.
+    #[inline(always)]
+    pub fn new(tlv_data: &'a [u8]) -> Self {
+        Self {
+            tlv_data,
+            offset: 0,
+        }
+    }
+
+    /// Create an iterator over the TLV area of the complete data of a mint or
+    /// token account.
+    #[inline]
+    pub fn from_account_data(
+        data: &'a [u8],
+        account_type: AccountType,
+    ) -> Result<Self, ProgramError> {
+        tlv_data(data, account_type).map(Self::new)
+    }
+
+    /// Return an iterator over the types of the extensions present.
+    #[inline]
+    pub fn extension_types(self) -> impl Iterator<Item = Result<ExtensionType, ProgramError>> + 'a {
+        self.map(|entry| entry.map(|(ty, _)| ty))
+    }
+}
+
+impl<'a> Iterator for ExtensionIter<'a> {
+    type Item = Result<(ExtensionType, &'a [u8]), ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let remaining = self.tlv_data.get(self.offset..)?;
+
+            if remaining.is_empty() {
+                return None;
+            }
+
+            // Trailing bytes that cannot hold a header are only valid as
+            // padding, e.g., a lone `Uninitialized` type.
+            if remaining.len() < TLV_HEADER_LEN {
+                self.offset = self.tlv_data.len();
+
+                return if remaining.iter().all(|byte| *byte == 0) {
+                    None
+                } else {
+                    Some(Err(ProgramError::InvalidAccountData))
+                };
+            }
+
+            let ty = u16::from_le_bytes([remaining[0], remaining[1]]);
+
+            // The remaining of the data is zero-padded.
+            if ty == ExtensionType::Uninitialized as u16 {
+                self.offset = self.tlv_data.len();
+                return None;
+            }
+
+            let len = u16::from_le_bytes([remaining[2], remaining[3]]) as usize;
+
+            let Some(value) = remaining.get(TLV_HEADER_LEN..TLV_HEADER_LEN + len) else {
+                self.offset = self.tlv_data.len();
+                return Some(Err(ProgramError::InvalidAccountData));
+            };
+
+            self.offset += TLV_HEADER_LEN + len;
+
+            // Extensions added after this crate was released are skipped.
+            if let Ok(ty) = ExtensionType::try_from(ty) {
+                return Some(Ok((ty, value)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    /// Return the complete data of a token account holding the given TLV area.
+    fn account_data<const N: usize>(tlv: &[u8]) -> [u8; N] {
+        let mut data = [0; N];
+        data[ACCOUNT_TYPE_OFFSET] = AccountType::Account as u8;
+        data[ACCOUNT_TYPE_OFFSET + 1..ACCOUNT_TYPE_OFFSET + 1 + tlv.len()].copy_from_slice(tlv);
+        data
+    }
+
+    #[test]
+    fn iterate_entries() {
+        let tlv = [1, 0, 2, 0, 0xaa, 0xbb, 3, 0, 1, 0, 0xcc];
+        let mut iter = ExtensionIter::new(&tlv);
+
+        assert_eq!(
+            iter.next(),
+            Some(Ok((ExtensionType::TransferFeeConfig, &[0xaa, 0xbb][..])))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Ok((ExtensionType::MintCloseAuthority, &[0xcc][..])))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn skip_unknown_extension_type() {
+        let tlv = [0xff, 0xff, 1, 0, 0xaa, 8, 0, 1, 0, 1];
+        let mut iter = ExtensionIter::new(&tlv).extension_types();
+
+        assert_eq!(iter.next(), Some(Ok(ExtensionType::MemoTransfer)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn truncated_header() {
+        for tlv in [&[8][..], &[8, 0], &[8, 0, 1], &[0, 0, 1]] {
+            let mut iter = ExtensionIter::new(tlv);
+
+            assert_eq!(iter.next(), Some(Err(ProgramError::InvalidAccountData)));
+            assert_eq!(iter.next(), None);
+        }
+
+        let tlv = [8, 0, 1, 0, 1, 8, 0];
+        let mut iter = ExtensionIter::new(&tlv);
+
+        assert!(matches!(iter.next(), Some(Ok(_))));
+        assert_eq!(iter.next(), Some(Err(ProgramError::InvalidAccountData)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn truncated_value() {
+        let tlv = [8, 0, 2, 0, 1];
+        let mut iter = ExtensionIter::new(&tlv);
+
+        assert_eq!(iter.next(), Some(Err(ProgramError::InvalidAccountData)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn padding() {
+        for padding in [&[0][..], &[0, 0], &[0, 0, 0], &[0, 0, 0, 0, 0, 0]] {
+            let mut tlv = [0; 11];
+            tlv[..5].copy_from_slice(&[8, 0, 1, 0, 1]);
+            let tlv = &tlv[..5 + padding.len()];
+
+            let mut iter = ExtensionIter::new(tlv);
+
+            assert_eq!(
+                iter.next(),
+                Some(Ok((ExtensionType::MemoTransfer, &[1][..])))
+            );
+            assert_eq!(iter.next(), None);
+        }
+
+        // An `Uninitialized` header ends the entries.
+        let tlv = [0, 0, 1, 0, 1, 8, 0, 1, 0, 1];
+
+        assert_eq!(ExtensionIter::new(&tlv).next(), None);
+    }
+
+    #[test]
+    fn find_missing_and_malformed_extension() {
+        let tlv = [1, 0, 2, 0, 0xaa, 0xbb];
+
+        assert_eq!(find_extension(&tlv, ExtensionType::MemoTransfer), Ok(None));
+        assert_eq!(
+            find_extension(&tlv, ExtensionType::TransferFeeConfig),
+            Ok(Some(&[0xaa, 0xbb][..]))
+        );
+
+        let tlv = [1, 0, 3, 0, 0xaa, 0xbb];
+
+        assert_eq!(
+            find_extension(&tlv, ExtensionType::MemoTransfer),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn extension_from_account_data() {
+        let data = account_data::<171>(&[8, 0, 1, 0, 1]);
+        let memo_transfer = MemoTransfer::from_account_data(&data).unwrap().unwrap();
+
+        assert!(memo_transfer.require_incoming_transfer_memos());
+
+        // Extension not present.
+        let data = account_data::<171>(&[11, 0, 1, 0, 1]);
+
+        assert!(MemoTransfer::from_account_data(&data).unwrap().is_none());
+
+        // Account without extensions.
+        assert!(
+            MemoTransfer::from_account_data(&[0; TokenAccount::BASE_LEN])
+                .unwrap()
+                .is_none()
+        );
+
+        // Value with the wrong length.
+        let data = account_data::<172>(&[8, 0, 2, 0, 1, 0]);
+
+        assert!(MemoTransfer::from_account_data(&data).is_err());
+
+        // Wrong account type.
+        let mut data = account_data::<171>(&[8, 0, 1, 0, 1]);
+        data[ACCOUNT_TYPE_OFFSET] = AccountType::Mint as u8;
+
+        assert!(MemoTransfer::from_account_data(&data).is_err());
+    }
+
+    #[test]
+    fn reject_multisig_length() {
+        let data = account_data::<{ Multisig::LEN }>(&[8, 0, 1, 0, 1]);
+
+        assert_eq!(
+            tlv_data(&data, AccountType::Account),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn powi_matches_intrinsic() {
+        for base in [10., 0.5, 1.0512710963760241, -3., 7.25] {
+            for exponent in -40..=40 {
+                assert_eq!(
+                    powi(base, exponent).to_bits(),
+                    base.powi(exponent).to_bits(),
+                    "{base}^{exponent}"
+                );
+            }
+        }
+
+        for decimals in 0..=u8::MAX {
+            assert_eq!(
+                powi(10., decimals as i32).to_bits(),
+                10f64.powi(decimals as i32).to_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn write_ui_amount_trims_like_token_2022() {
+        let mut buffer = [0; 64];
+
+        for (ui_amount, decimals, expected) in [
+            (0.23, 2, "0.23"),
+            (1.1, 2, "1.1"),
+            (42., 2, "42"),
+            (0., 2, "0"),
+            (0., 0, "0"),
+            (4200., 0, "4200"),
+            (1.0512710963760241, 10, "1.0512710964"),
+            (0.5, 0, "0"),
+            (1.5, 0, "2"),
+            (-0.25, 2, "-0.25"),
+        ] {
+            assert_eq!(
+                write_ui_amount(ui_amount, decimals, &mut buffer),
+                Some(expected),
+                "{ui_amount} with {decimals} decimals"
+            );
+        }
+
+        // Buffer too small.
+        assert_eq!(write_ui_amount(123.456, 3, &mut buffer[..6]), None);
+        assert_eq!(
+            write_ui_amount(123.456, 3, &mut buffer[..7]),
+            Some("123.456")
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Pausable extension data for mints.
+#[repr(C)]
+pub struct PausableConfig {
+    /// Authority that can pause or resume activity on the mint.
+    authority: Address,
+
+    /// Whether minting, burning and transferring are paused.
+    paused: u8,
+}
+
+impl Extension for PausableConfig {
+    const TYPE: ExtensionType = ExtensionType::Pausable;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const PausableConfig)
+    }
+}
+
+impl PausableConfig {
+    /// Optional authority that can pause or resume the mint.
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    /// Indicates whether minting, burning and transferring are paused.
+    #[inline(always)]
+    pub fn paused(&self) -> bool {
+        self.paused != 0
+    }
+}
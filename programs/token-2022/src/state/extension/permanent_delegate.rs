@@ -0,0 +1,28 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Permanent delegate extension data for mints.
+#[repr(C)]
+pub struct PermanentDelegate {
+    /// Optional permanent delegate for transferring or burning tokens.
+    delegate: Address,
+}
+
+impl Extension for PermanentDelegate {
+    const TYPE: ExtensionType = ExtensionType::PermanentDelegate;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const PermanentDelegate)
+    }
+}
+
+impl PermanentDelegate {
+    /// Optional permanent delegate that can transfer or burn any tokens of the mint.
+    pub fn delegate(&self) -> Option<&Address> {
+        optional_address(&self.delegate)
+    }
+}
@@ -0,0 +1,8 @@
+/// Encoded ElGamal public key.
+pub type PodElGamalPubkey = [u8; 32];
+
+/// Encoded ElGamal ciphertext.
+pub type PodElGamalCiphertext = [u8; 64];
+
+/// Encoded authenticated encryption ciphertext, used for decryptable balances.
+pub type PodAeCiphertext = [u8; 36];
@@ -0,0 +1,370 @@
+use pinocchio::sysvars::clock::Clock;
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+use super::{optional_address, powi, write_ui_amount, AccountType, Extension, ExtensionType};
+
+/// Scaled UI amount extension data for mints.
+///
+/// The UI amount of tokens is their raw amount multiplied by the active
+/// multiplier. A new multiplier takes effect at its effective timestamp.
+#[repr(C)]
+pub struct ScaledUiAmountConfig {
+    /// Authority that can set the scaling amount and authority.
+    authority: Address,
+
+    /// Amount to multiply raw amounts by, outside of the decimal.
+    multiplier: [u8; 8],
+
+    /// Unix timestamp at which `new_multiplier` comes into effect.
+    new_multiplier_effective_timestamp: [u8; 8],
+
+    /// Next multiplier, once `new_multiplier_effective_timestamp` is reached.
+    new_multiplier: [u8; 8],
+}
+
+impl Extension for ScaledUiAmountConfig {
+    const TYPE: ExtensionType = ExtensionType::ScaledUiAmount;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const ScaledUiAmountConfig)
+    }
+}
+
+impl ScaledUiAmountConfig {
+    /// Optional authority that can set the multiplier.
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    /// Multiplier applied to the UI amount before the new multiplier takes effect.
+    #[inline(always)]
+    pub fn multiplier(&self) -> f64 {
+        f64::from_le_bytes(self.multiplier)
+    }
+
+    /// Timestamp from which the new multiplier takes effect.
+    #[inline(always)]
+    pub fn new_multiplier_effective_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.new_multiplier_effective_timestamp)
+    }
+
+    /// Multiplier applied to the UI amount from its effective timestamp.
+    #[inline(always)]
+    pub fn new_multiplier(&self) -> f64 {
+        f64::from_le_bytes(self.new_multiplier)
+    }
+
+    /// Return the multiplier in effect at the time of the given clock.
+    #[inline(always)]
+    pub fn current_multiplier(&self, clock: &Clock) -> f64 {
+        if clock.unix_timestamp >= self.new_multiplier_effective_timestamp() {
+            self.new_multiplier()
+        } else {
+            self.multiplier()
+        }
+    }
+
+    /// Return the scale to convert an amount of tokens to its scaled UI amount
+    /// at the time of the given clock.
+    #[inline(always)]
+    pub fn total_multiplier(&self, decimals: u8, clock: &Clock) -> f64 {
+        self.current_multiplier(clock) / powi(10., decimals as i32)
+    }
+
+    /// Convert a raw amount of tokens to its scaled UI amount.
+    ///
+    /// The scaled amount is truncated to a whole number of raw tokens before
+    /// being written to `buffer` with trailing zeros trimmed. Returns `None` if
+    /// `buffer` is too small.
+    pub fn amount_to_ui_amount<'a>(
+        &self,
+        amount: u64,
+        decimals: u8,
+        clock: &Clock,
+        buffer: &'a mut [u8],
+    ) -> Option<&'a str> {
+        let scaled_amount = libm::trunc((amount as f64) * self.current_multiplier(clock));
+        write_ui_amount(scaled_amount / powi(10., decimals as i32), decimals, buffer)
+    }
+
+    /// Convert a scaled UI amount to its raw amount of tokens.
+    ///
+    /// The raw amount is truncated, giving the fewest tokens that result in the
+    /// UI amount.
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        clock: &Clock,
+    ) -> Result<u64, ProgramError> {
+        let ui_amount = ui_amount
+            .parse::<f64>()
+            .map_err(|_| ProgramError::InvalidArgument)?;
+        let amount = ui_amount / self.total_multiplier(decimals, clock);
+
+        if amount > (u64::MAX as f64) || amount < (u64::MIN as f64) || amount.is_nan() {
+            Err(ProgramError::InvalidArgument)
+        } else {
+            // Truncation must happen after the division, otherwise large amounts
+            // would overflow to infinity.
+            Ok(libm::trunc(amount) as u64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DECIMALS: u8 = 2;
+
+    fn config(
+        multiplier: f64,
+        new_multiplier: f64,
+        new_multiplier_effective_timestamp: i64,
+    ) -> ScaledUiAmountConfig {
+        ScaledUiAmountConfig {
+            authority: Address::new_from_array([0; 32]),
+            multiplier: multiplier.to_le_bytes(),
+            new_multiplier_effective_timestamp: new_multiplier_effective_timestamp.to_le_bytes(),
+            new_multiplier: new_multiplier.to_le_bytes(),
+        }
+    }
+
+    fn clock(unix_timestamp: i64) -> Clock {
+        Clock {
+            slot: 0,
+            epoch_start_timestamp: 0,
+            epoch: 0,
+            leader_schedule_epoch: 0,
+            unix_timestamp,
+        }
+    }
+
+    /// Return a config with the given multiplier, read at timestamp `0`.
+    fn constant(multiplier: f64) -> ScaledUiAmountConfig {
+        config(multiplier, 0., 1)
+    }
+
+    fn assert_ui_amount(config: &ScaledUiAmountConfig, amount: u64, decimals: u8, expected: &str) {
+        let mut buffer = [0; 512];
+
+        assert_eq!(
+            config.amount_to_ui_amount(amount, decimals, &clock(0), &mut buffer),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn multiplier_choice() {
+        let multiplier = 5.0;
+        let new_multiplier = 10.0;
+        let new_multiplier_effective_timestamp = 1;
+        let config = config(
+            multiplier,
+            new_multiplier,
+            new_multiplier_effective_timestamp,
+        );
+
+        assert_eq!(
+            config.total_multiplier(0, &clock(new_multiplier_effective_timestamp)),
+            new_multiplier
+        );
+        assert_eq!(
+            config.total_multiplier(0, &clock(new_multiplier_effective_timestamp - 1)),
+            multiplier
+        );
+        assert_eq!(config.total_multiplier(0, &clock(0)), multiplier);
+        assert_eq!(config.total_multiplier(0, &clock(i64::MIN)), multiplier);
+        assert_eq!(config.total_multiplier(0, &clock(i64::MAX)), new_multiplier);
+
+        // The UI amount switches at the effective timestamp.
+        let mut buffer = [0; 16];
+        assert_eq!(
+            config.amount_to_ui_amount(3, 0, &clock(0), &mut buffer),
+            Some("15")
+        );
+        assert_eq!(
+            config.amount_to_ui_amount(3, 0, &clock(1), &mut buffer),
+            Some("30")
+        );
+        assert_eq!(config.try_ui_amount_into_amount("30", 0, &clock(0)), Ok(6));
+        assert_eq!(config.try_ui_amount_into_amount("30", 0, &clock(1)), Ok(3));
+    }
+
+    #[test]
+    fn specific_amount_to_ui_amount() {
+        // 5x.
+        let config = constant(5.0);
+        assert_ui_amount(&config, 1, 0, "5");
+        // With 1 decimal place.
+        assert_ui_amount(&config, 1, 1, "0.5");
+        // With 10 decimal places.
+        assert_ui_amount(&config, 1, 10, "0.0000000005");
+        // Huge amount with 10 decimal places.
+        assert_ui_amount(&config, 10_000_000_000, 10, "5");
+
+        // Huge values.
+        assert_ui_amount(&constant(f64::MAX), u64::MAX, 0, "inf");
+
+        // Truncation, this is really 0.99999... but it gets truncated.
+        assert_ui_amount(&constant(0.99), 101, 2, "0.99");
+    }
+
+    #[test]
+    fn specific_ui_amount_to_amount() {
+        let clock = clock(0);
+
+        // Constant 5x.
+        let config = constant(5.0);
+        assert_eq!(config.try_ui_amount_into_amount("5.0", 0, &clock), Ok(1));
+        // With 1 decimal place.
+        assert_eq!(
+            config.try_ui_amount_into_amount("0.500000000", 1, &clock),
+            Ok(1)
+        );
+        // With 10 decimal places.
+        assert_eq!(
+            config.try_ui_amount_into_amount("0.00000000050000000000000000", 10, &clock),
+            Ok(1)
+        );
+        // Huge amount with 10 decimal places.
+        assert_eq!(
+            config.try_ui_amount_into_amount("5.0000000000000000", 10, &clock),
+            Ok(10_000_000_000)
+        );
+
+        // Huge values.
+        assert_eq!(
+            config.try_ui_amount_into_amount("92233720368547758075", 0, &clock),
+            Ok(u64::MAX)
+        );
+        // Scientific notation "e".
+        let config = constant(f64::MAX);
+        assert_eq!(
+            config.try_ui_amount_into_amount("1.7976931348623157e308", 0, &clock),
+            Ok(1)
+        );
+        let config = constant(9.745314011399998e288);
+        assert_eq!(
+            config.try_ui_amount_into_amount("1.7976931348623157e308", 0, &clock),
+            Ok(u64::MAX)
+        );
+        // Scientific notation "E".
+        assert_eq!(
+            config.try_ui_amount_into_amount("1.7976931348623157E308", 0, &clock),
+            Ok(u64::MAX)
+        );
+
+        // This is unfortunate, but underflows can happen due to floats.
+        let config = constant(1.0);
+        assert_eq!(
+            config.try_ui_amount_into_amount("18446744073709551616", 0, &clock),
+            Ok(u64::MAX)
+        );
+
+        // Overflow u64 fail.
+        let config = constant(0.1);
+        assert_eq!(
+            config.try_ui_amount_into_amount("18446744073709551615", 0, &clock),
+            Err(ProgramError::InvalidArgument)
+        );
+
+        for fail_ui_amount in ["-0.0000000000000000000001", "inf", "-inf", "NaN"] {
+            assert_eq!(
+                config.try_ui_amount_into_amount(fail_ui_amount, 0, &clock),
+                Err(ProgramError::InvalidArgument)
+            );
+        }
+
+        // Truncation, "0.99" could be 101 or 100 underlying tokens, but the
+        // result gives the fewest possible tokens that give that UI amount.
+        let config = constant(0.99);
+        assert_eq!(config.try_ui_amount_into_amount("0.99", 2, &clock), Ok(100));
+    }
+
+    #[test]
+    fn specific_amount_to_ui_amount_no_scale() {
+        let config = constant(1.0);
+
+        for (amount, expected) in [(23, "0.23"), (110, "1.1"), (4200, "42"), (0, "0")] {
+            assert_ui_amount(&config, amount, TEST_DECIMALS, expected);
+        }
+    }
+
+    #[test]
+    fn specific_ui_amount_to_amount_no_scale() {
+        let config = constant(1.0);
+        let clock = clock(0);
+
+        for (ui_amount, expected) in [
+            ("0.23", 23),
+            ("0.20", 20),
+            ("0.2000", 20),
+            (".2", 20),
+            ("1.1", 110),
+            ("1.10", 110),
+            ("42", 4200),
+            ("42.", 4200),
+            ("0", 0),
+        ] {
+            assert_eq!(
+                config.try_ui_amount_into_amount(ui_amount, TEST_DECIMALS, &clock),
+                Ok(expected)
+            );
+        }
+
+        // This is invalid with normal mints, but rounding for this mint makes it ok.
+        assert_eq!(
+            config.try_ui_amount_into_amount("0.111", TEST_DECIMALS, &clock),
+            Ok(11)
+        );
+
+        // Fail if invalid ui_amount passed in.
+        for ui_amount in ["", ".", "0.t"] {
+            assert_eq!(
+                config.try_ui_amount_into_amount(ui_amount, TEST_DECIMALS, &clock),
+                Err(ProgramError::InvalidArgument)
+            );
+        }
+    }
+
+    #[test]
+    fn ui_amount_round_trip() {
+        let clock = clock(0);
+        let mut buffer = [0; 64];
+
+        // Whole multipliers and UI amounts are exact in floating point, so the
+        // raw amount survives the round trip.
+        for multiplier in [1.0, 2.0, 5.0, 1_000.0] {
+            let config = constant(multiplier);
+
+            for amount in [0, 1, 7, 100, 12_345, 1_000_000_000] {
+                let ui_amount = config
+                    .amount_to_ui_amount(amount, 0, &clock, &mut buffer)
+                    .unwrap();
+
+                assert_eq!(
+                    config.try_ui_amount_into_amount(ui_amount, 0, &clock),
+                    Ok(amount),
+                    "{ui_amount} with multiplier {multiplier}"
+                );
+            }
+        }
+
+        // Decimals are applied to the UI amount only.
+        let config = constant(2.0);
+        assert_eq!(
+            config.amount_to_ui_amount(12_345, 2, &clock, &mut buffer),
+            Some("246.9")
+        );
+        assert_eq!(
+            config.try_ui_amount_into_amount("246.9", 2, &clock),
+            Ok(12_345)
+        );
+    }
+}
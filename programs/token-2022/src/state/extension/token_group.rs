@@ -0,0 +1,107 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Token group extension data for mints.
+#[repr(C)]
+pub struct TokenGroup {
+    /// The authority that can sign to update the group.
+    update_authority: Address,
+
+    /// The associated mint, used to counter spoofing to be sure that group
+    /// belongs to a particular mint.
+    mint: Address,
+
+    /// The current number of group members.
+    size: [u8; 8],
+
+    /// The maximum number of group members.
+    max_size: [u8; 8],
+}
+
+impl Extension for TokenGroup {
+    const TYPE: ExtensionType = ExtensionType::TokenGroup;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TokenGroup)
+    }
+}
+
+impl TokenGroup {
+    /// Optional authority that can update the group.
+    pub fn update_authority(&self) -> Option<&Address> {
+        optional_address(&self.update_authority)
+    }
+
+    /// Mint of the group.
+    #[inline(always)]
+    pub fn mint(&self) -> &Address {
+        &self.mint
+    }
+
+    /// Current number of group members.
+    #[inline(always)]
+    pub fn size(&self) -> u64 {
+        u64::from_le_bytes(self.size)
+    }
+
+    /// Maximum number of group members.
+    #[inline(always)]
+    pub fn max_size(&self) -> u64 {
+        u64::from_le_bytes(self.max_size)
+    }
+
+    /// Indicates whether the group has reached its maximum number of members.
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.size() >= self.max_size()
+    }
+}
+
+/// Token group member extension data for mints.
+#[repr(C)]
+pub struct TokenGroupMember {
+    /// The associated mint, used to counter spoofing to be sure that member
+    /// belongs to a particular mint.
+    mint: Address,
+
+    /// The pubkey of the `TokenGroup`.
+    group: Address,
+
+    /// The member number.
+    member_number: [u8; 8],
+}
+
+impl Extension for TokenGroupMember {
+    const TYPE: ExtensionType = ExtensionType::TokenGroupMember;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TokenGroupMember)
+    }
+}
+
+impl TokenGroupMember {
+    /// Mint of the group member.
+    #[inline(always)]
+    pub fn mint(&self) -> &Address {
+        &self.mint
+    }
+
+    /// Address of the group the member belongs to.
+    #[inline(always)]
+    pub fn group(&self) -> &Address {
+        &self.group
+    }
+
+    /// Unique member number within the group.
+    #[inline(always)]
+    pub fn member_number(&self) -> u64 {
+        u64::from_le_bytes(self.member_number)
+    }
+}
@@ -0,0 +1,450 @@
+use core::str::from_utf8;
+
+use solana_account_view::{AccountView, Ref};
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+use super::{find_extension, optional_address, tlv_data, AccountType, ExtensionType};
+
+use crate::ID;
+
+/// Token metadata extension data for mints.
+///
+/// The extension has a variable length, so this type holds references into the
+/// account data instead of being a view over a fixed layout. All fields are
+/// validated when the view is created.
+pub struct TokenMetadata<'a> {
+    /// The authority that can sign to update the metadata.
+    update_authority: &'a Address,
+
+    /// The associated mint, used to counter spoofing to be sure that metadata
+    /// belongs to a particular mint.
+    mint: &'a Address,
+
+    /// The longer name of the token.
+    name: &'a str,
+
+    /// The shortened symbol for the token.
+    symbol: &'a str,
+
+    /// The URI pointing to richer metadata.
+    uri: &'a str,
+
+    /// Number of additional key-value pairs.
+    additional_metadata_len: usize,
+
+    /// Serialized additional key-value pairs.
+    additional_metadata: &'a [u8],
+}
+
+impl<'a> TokenMetadata<'a> {
+    /// Return a `TokenMetadata` from the complete data of a mint account.
+    ///
+    /// `None` is returned if the mint does not hold the extension.
+    #[inline]
+    pub fn from_account_data(data: &'a [u8]) -> Result<Option<Self>, ProgramError> {
+        find_extension(
+            tlv_data(data, AccountType::Mint)?,
+            ExtensionType::TokenMetadata,
+        )?
+        .map(Self::from_bytes)
+        .transpose()
+    }
+
+    /// Return a `TokenMetadata` from the given mint account view.
+    ///
+    /// This method performs owner, account type and TLV validation on `AccountView`,
+    /// safe borrowing the account data. The metadata is available through the
+    /// returned [`TokenMetadataRef`], which holds the borrow. `None` is returned
+    /// if the mint does not hold the extension.
+    #[inline]
+    pub fn from_account_view(
+        account_view: &AccountView,
+    ) -> Result<Option<TokenMetadataRef<'_>>, ProgramError> {
+        if !account_view.owned_by(&ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        match Ref::try_map(account_view.try_borrow()?, |data| {
+            let value = find_extension(
+                tlv_data(data, AccountType::Mint)?,
+                ExtensionType::TokenMetadata,
+            )?
+            .ok_or(None)?;
+            TokenMetadata::from_bytes(value)?;
+            Ok(value)
+        }) {
+            Ok(value) => Ok(Some(TokenMetadataRef { value })),
+            Err((_, None)) => Ok(None),
+            Err((_, Some(error))) => Err(error),
+        }
+    }
+
+    /// Return a `TokenMetadata` from the given mint account view.
+    ///
+    /// This method performs owner, account type and TLV validation on `AccountView`,
+    /// but does not perform the borrow check. `None` is returned if the mint does
+    /// not hold the extension.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is safe to borrow the account data (e.g., there are
+    /// no mutable borrows of the account data) for the lifetime of the returned value.
+    #[inline]
+    pub unsafe fn from_account_view_unchecked(
+        account_view: &'a AccountView,
+    ) -> Result<Option<Self>, ProgramError> {
+        if account_view.owner() != &ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        Self::from_account_data(account_view.borrow_unchecked())
+    }
+
+    /// Return a `TokenMetadata` from the value bytes of the extension.
+    ///
+    /// This method validates the length of every field, that the strings are valid
+    /// UTF-8 and that no bytes follow the additional key-value pairs.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        if bytes.len() < 64 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // SAFETY: `bytes` has at least 64 bytes and `Address` has an alignment of 1 byte.
+        let (update_authority, mint) = unsafe {
+            (
+                &*(bytes.as_ptr() as *const Address),
+                &*(bytes[32..].as_ptr() as *const Address),
+            )
+        };
+
+        let (name, offset) = read_str(bytes, 64)?;
+        let (symbol, offset) = read_str(bytes, offset)?;
+        let (uri, offset) = read_str(bytes, offset)?;
+        let (additional_metadata_len, offset) = read_u32(bytes, offset)?;
+        let additional_metadata = &bytes[offset..];
+
+        // Validate the key-value pairs so they can be iterated without errors.
+        let mut offset = 0;
+
+        for _ in 0..additional_metadata_len {
+            let (_, key_end) = read_str(additional_metadata, offset)?;
+            let (_, value_end) = read_str(additional_metadata, key_end)?;
+            offset = value_end;
+        }
+
+        if offset != additional_metadata.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            update_authority,
+            mint,
+            name,
+            symbol,
+            uri,
+            additional_metadata_len: additional_metadata_len as usize,
+            additional_metadata,
+        })
+    }
+
+    /// Optional authority that can update the metadata.
+    pub fn update_authority(&self) -> Option<&'a Address> {
+        optional_address(self.update_authority)
+    }
+
+    /// Mint the metadata belongs to.
+    #[inline(always)]
+    pub fn mint(&self) -> &'a Address {
+        self.mint
+    }
+
+    /// Longer name of the token.
+    #[inline(always)]
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Shortened symbol of the token.
+    #[inline(always)]
+    pub fn symbol(&self) -> &'a str {
+        self.symbol
+    }
+
+    /// URI pointing to richer metadata.
+    #[inline(always)]
+    pub fn uri(&self) -> &'a str {
+        self.uri
+    }
+
+    /// Number of additional key-value pairs.
+    #[inline(always)]
+    pub fn additional_metadata_len(&self) -> usize {
+        self.additional_metadata_len
+    }
+
+    /// Return an iterator over the additional key-value pairs.
+    #[inline]
+    pub fn additional_metadata(&self) -> AdditionalMetadataIter<'a> {
+        AdditionalMetadataIter {
+            data: self.additional_metadata,
+            offset: 0,
+        }
+    }
+
+    /// Return the value of the first additional key-value pair with the given key.
+    pub fn get_additional_metadata(&self, key: &str) -> Option<&'a str> {
+        self.additional_metadata()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Token metadata extension of a mint account view, holding a borrow of the
+/// account data.
+pub struct TokenMetadataRef<'a> {
+    /// Value bytes of the extension, validated on creation.
+    value: Ref<'a, [u8]>,
+}
+
+impl TokenMetadataRef<'_> {
+    /// Return the token metadata.
+    #[inline]
+    pub fn get(&self) -> TokenMetadata<'_> {
+        // SAFETY: the value bytes were validated when the `TokenMetadataRef` was created.
+        unsafe { TokenMetadata::from_bytes(&self.value).unwrap_unchecked() }
+    }
+}
+
+/// Iterator over the additional key-value pairs of a `TokenMetadata`.
+pub struct AdditionalMetadataIter<'a> {
+    /// Serialized (and validated) key-value pairs.
+    data: &'a [u8],
+
+    /// Offset of the next pair.
+    offset: usize,
+}
+
+impl<'a> Iterator for AdditionalMetadataIter<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+
+        // The pairs were validated when the `TokenMetadata` was created.
+        let (key, offset) = read_str(self.data, self.offset).ok()?;
+        let (value, offset) = read_str(self.data, offset).ok()?;
+        self.offset = offset;
+
+        Some((key, value))
+    }
+}
+
+/// Read a borsh-encoded `u32` at `offset`, returning the offset after it.
+#[inline(always)]
+fn read_u32(bytes: &[u8], offset: usize) -> Result<(u32, usize), ProgramError> {
+    let end = offset + 4;
+    let value = bytes
+        .get(offset..end)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok((
+        u32::from_le_bytes([value[0], value[1], value[2], value[3]]),
+        end,
+    ))
+}
+
+/// Read a borsh-encoded string at `offset`, returning the offset after it.
+#[inline(always)]
+fn read_str(bytes: &[u8], offset: usize) -> Result<(&str, usize), ProgramError> {
+    let (len, offset) = read_u32(bytes, offset)?;
+    let end = offset
+        .checked_add(len as usize)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let value = bytes
+        .get(offset..end)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok((
+        from_utf8(value).map_err(|_| ProgramError::InvalidAccountData)?,
+        end,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use solana_address::Address;
+    use solana_program_error::ProgramError;
+
+    use super::TokenMetadata;
+    use crate::state::{extension::ACCOUNT_TYPE_OFFSET, test_utils::make_account_view};
+    use crate::ID;
+
+    /// Append a borsh-encoded string.
+    fn push_str(bytes: &mut Vec<u8>, value: &[u8]) {
+        bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(value);
+    }
+
+    /// Return the borsh encoding of a `TokenMetadata` from
+    /// `spl-token-metadata-interface`.
+    fn metadata_bytes(update_authority: [u8; 32], pairs: &[(&str, &str)]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&update_authority);
+        bytes.extend_from_slice(&[2; 32]);
+        push_str(&mut bytes, b"name");
+        push_str(&mut bytes, b"SYM");
+        push_str(&mut bytes, b"https://example.com");
+        bytes.extend_from_slice(&(pairs.len() as u32).to_le_bytes());
+
+        for (key, value) in pairs {
+            push_str(&mut bytes, key.as_bytes());
+            push_str(&mut bytes, value.as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Return the complete data of a mint holding the given extension value.
+    fn mint_data(value: &[u8]) -> Vec<u8> {
+        let mut data = alloc::vec![0; ACCOUNT_TYPE_OFFSET];
+        data.push(1);
+        data.extend_from_slice(&19u16.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+        data
+    }
+
+    #[test]
+    fn read_metadata() {
+        let bytes = metadata_bytes([1; 32], &[("key", "value"), ("other", "")]);
+        let metadata = TokenMetadata::from_bytes(&bytes).unwrap();
+
+        assert_eq!(
+            metadata.update_authority(),
+            Some(&Address::new_from_array([1; 32]))
+        );
+        assert_eq!(metadata.mint(), &Address::new_from_array([2; 32]));
+        assert_eq!(metadata.name(), "name");
+        assert_eq!(metadata.symbol(), "SYM");
+        assert_eq!(metadata.uri(), "https://example.com");
+        assert_eq!(metadata.additional_metadata_len(), 2);
+        assert_eq!(
+            metadata.additional_metadata().collect::<Vec<_>>(),
+            [("key", "value"), ("other", "")]
+        );
+        assert_eq!(metadata.get_additional_metadata("other"), Some(""));
+        assert_eq!(metadata.get_additional_metadata("missing"), None);
+
+        let bytes = metadata_bytes([0; 32], &[]);
+        let metadata = TokenMetadata::from_bytes(&bytes).unwrap();
+
+        assert_eq!(metadata.update_authority(), None);
+        assert_eq!(metadata.additional_metadata().next(), None);
+    }
+
+    #[test]
+    fn reject_truncated_metadata() {
+        let bytes = metadata_bytes([1; 32], &[("key", "value")]);
+
+        // Every prefix is missing part of a field.
+        for len in 0..bytes.len() {
+            assert_eq!(
+                TokenMetadata::from_bytes(&bytes[..len]).err(),
+                Some(ProgramError::InvalidAccountData),
+                "prefix of {len} bytes"
+            );
+        }
+
+        // String length past the end of the data.
+        let mut bytes = metadata_bytes([1; 32], &[]);
+        bytes[64..68].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            TokenMetadata::from_bytes(&bytes).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn reject_invalid_utf8() {
+        let mut bytes = metadata_bytes([1; 32], &[]);
+        // First byte of the name.
+        bytes[68] = 0xff;
+        assert_eq!(
+            TokenMetadata::from_bytes(&bytes).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let mut bytes = metadata_bytes([1; 32], &[("key", "value")]);
+        // Last byte of the additional metadata value.
+        let last = bytes.len() - 1;
+        bytes[last] = 0xc3;
+        assert_eq!(
+            TokenMetadata::from_bytes(&bytes).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn reject_mismatched_additional_metadata() {
+        // More pairs than serialized.
+        let mut bytes = metadata_bytes([1; 32], &[("key", "value")]);
+        let count = bytes.len() - 20;
+        bytes[count..count + 4].copy_from_slice(&2u32.to_le_bytes());
+        assert_eq!(
+            TokenMetadata::from_bytes(&bytes).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // Bytes after the last pair.
+        let mut bytes = metadata_bytes([1; 32], &[("key", "value")]);
+        bytes.push(0);
+        assert_eq!(
+            TokenMetadata::from_bytes(&bytes).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let mut bytes = metadata_bytes([1; 32], &[]);
+        push_str(&mut bytes, b"key");
+        assert_eq!(
+            TokenMetadata::from_bytes(&bytes).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn read_metadata_from_mint() {
+        let data = mint_data(&metadata_bytes([1; 32], &[("key", "value")]));
+
+        let metadata = TokenMetadata::from_account_data(&data).unwrap().unwrap();
+        assert_eq!(metadata.get_additional_metadata("key"), Some("value"));
+
+        let (account, _backing) = make_account_view(Address::default(), ID, false, &data);
+        let metadata = TokenMetadata::from_account_view(&account).unwrap().unwrap();
+        assert_eq!(metadata.get().name(), "name");
+
+        // Mint without the extension.
+        let mut data = alloc::vec![0; ACCOUNT_TYPE_OFFSET];
+        data.push(1);
+        assert!(TokenMetadata::from_account_data(&data).unwrap().is_none());
+
+        // Malformed extension value.
+        let mut value = metadata_bytes([1; 32], &[]);
+        value.pop();
+        let data = mint_data(&value);
+        assert_eq!(
+            TokenMetadata::from_account_data(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        let (account, _backing) = make_account_view(Address::default(), ID, false, &data);
+        assert_eq!(
+            TokenMetadata::from_account_view(&account).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
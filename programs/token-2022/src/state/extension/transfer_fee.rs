@@ -0,0 +1,407 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Maximum possible fee in basis points is `100%`, aka 10_000 basis points.
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+const ONE_IN_BASIS_POINTS: u128 = MAX_FEE_BASIS_POINTS as u128;
+
+/// Transfer fee information.
+#[repr(C)]
+pub struct TransferFee {
+    /// First epoch where the transfer fee takes effect.
+    epoch: [u8; 8],
+
+    /// Maximum fee assessed on transfers, expressed as an amount of tokens.
+    maximum_fee: [u8; 8],
+
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount, ie. increments of 0.01%.
+    transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    /// First epoch where the transfer fee takes effect.
+    #[inline(always)]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    /// Maximum fee assessed on transfers, expressed as an amount of tokens.
+    #[inline(always)]
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    /// Amount of transfer collected as fees, expressed as basis points of the
+    /// transfer amount.
+    #[inline(always)]
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Calculate the transfer fee of the given pre-fee amount.
+    ///
+    /// The fee is rounded up and capped at the maximum fee.
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        let transfer_fee_basis_points = self.transfer_fee_basis_points() as u128;
+
+        if transfer_fee_basis_points == 0 || pre_fee_amount == 0 {
+            Some(0)
+        } else {
+            let numerator = (pre_fee_amount as u128).checked_mul(transfer_fee_basis_points)?;
+            let raw_fee = u64::try_from(ceil_div(numerator, ONE_IN_BASIS_POINTS)?).ok()?;
+
+            Some(raw_fee.min(self.maximum_fee()))
+        }
+    }
+
+    /// Calculate the amount received after the transfer fee is deducted from
+    /// the given pre-fee amount.
+    pub fn calculate_post_fee_amount(&self, pre_fee_amount: u64) -> Option<u64> {
+        pre_fee_amount.checked_sub(self.calculate_fee(pre_fee_amount)?)
+    }
+
+    /// Calculate the pre-fee amount required to receive the given post-fee
+    /// amount.
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        let maximum_fee = self.maximum_fee();
+        let transfer_fee_basis_points = self.transfer_fee_basis_points() as u128;
+
+        match (transfer_fee_basis_points, post_fee_amount) {
+            // No fee, the pre-fee amount is the same as the post-fee amount.
+            (0, _) => Some(post_fee_amount),
+            (_, 0) => Some(0),
+            // Fee is 100%, the maximum fee is always assessed.
+            (ONE_IN_BASIS_POINTS, _) => maximum_fee.checked_add(post_fee_amount),
+            _ => {
+                let numerator = (post_fee_amount as u128).checked_mul(ONE_IN_BASIS_POINTS)?;
+                let denominator = ONE_IN_BASIS_POINTS.checked_sub(transfer_fee_basis_points)?;
+                let raw_pre_fee_amount = ceil_div(numerator, denominator)?;
+
+                if raw_pre_fee_amount.checked_sub(post_fee_amount as u128)? >= maximum_fee as u128 {
+                    post_fee_amount.checked_add(maximum_fee)
+                } else {
+                    u64::try_from(raw_pre_fee_amount).ok()
+                }
+            }
+        }
+    }
+
+    /// Calculate the fee that would produce the given post-fee amount.
+    pub fn calculate_inverse_fee(&self, post_fee_amount: u64) -> Option<u64> {
+        self.calculate_fee(self.calculate_pre_fee_amount(post_fee_amount)?)
+    }
+}
+
+/// Transfer fee extension data for mints.
+#[repr(C)]
+pub struct TransferFeeConfig {
+    /// Optional authority to set the fee.
+    transfer_fee_config_authority: Address,
+
+    /// Withdraw from mint instructions must be signed by this key.
+    withdraw_withheld_authority: Address,
+
+    /// Withheld transfer fee tokens that have been moved to the mint for
+    /// withdrawal.
+    withheld_amount: [u8; 8],
+
+    /// Older transfer fee, used if the current epoch < `new_transfer_fee.epoch`.
+    older_transfer_fee: TransferFee,
+
+    /// Newer transfer fee, used if the current epoch >= `new_transfer_fee.epoch`.
+    newer_transfer_fee: TransferFee,
+}
+
+impl Extension for TransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferFeeConfig)
+    }
+}
+
+impl TransferFeeConfig {
+    /// Optional authority to set the fee.
+    pub fn transfer_fee_config_authority(&self) -> Option<&Address> {
+        optional_address(&self.transfer_fee_config_authority)
+    }
+
+    /// Optional authority to withdraw withheld fees from the mint.
+    pub fn withdraw_withheld_authority(&self) -> Option<&Address> {
+        optional_address(&self.withdraw_withheld_authority)
+    }
+
+    /// Withheld transfer fee tokens that have been moved to the mint for withdrawal.
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    /// Older transfer fee, used if the current epoch is before the newer fee epoch.
+    #[inline(always)]
+    pub fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    /// Newer transfer fee, used if the current epoch is at or after its epoch.
+    #[inline(always)]
+    pub fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    /// Return the transfer fee in effect at the given epoch.
+    #[inline]
+    pub fn get_epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+
+    /// Calculate the fee of the given pre-fee amount at the given epoch.
+    #[inline]
+    pub fn calculate_epoch_fee(&self, epoch: u64, pre_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch).calculate_fee(pre_fee_amount)
+    }
+
+    /// Calculate the fee that would produce the given post-fee amount at the
+    /// given epoch.
+    #[inline]
+    pub fn calculate_inverse_epoch_fee(&self, epoch: u64, post_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch)
+            .calculate_inverse_fee(post_fee_amount)
+    }
+}
+
+/// Transfer fee extension data for token accounts.
+#[repr(C)]
+pub struct TransferFeeAmount {
+    /// Amount withheld during transfers, to be harvested to the mint.
+    withheld_amount: [u8; 8],
+}
+
+impl Extension for TransferFeeAmount {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeAmount;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Account;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferFeeAmount)
+    }
+}
+
+impl TransferFeeAmount {
+    /// Amount withheld during transfers, to be harvested to the mint.
+    #[inline(always)]
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+}
+
+/// Divide rounding up.
+#[inline(always)]
+fn ceil_div(numerator: u128, denominator: u128) -> Option<u128> {
+    numerator
+        .checked_add(denominator)?
+        .checked_sub(1)?
+        .checked_div(denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: u64 = ONE_IN_BASIS_POINTS as u64;
+
+    fn transfer_fee(maximum_fee: u64, transfer_fee_basis_points: u16) -> TransferFee {
+        TransferFee {
+            epoch: 0u64.to_le_bytes(),
+            maximum_fee: maximum_fee.to_le_bytes(),
+            transfer_fee_basis_points: transfer_fee_basis_points.to_le_bytes(),
+        }
+    }
+
+    #[test]
+    fn calculate_fee_max() {
+        let transfer_fee = transfer_fee(5_000, 1);
+        let maximum_fee = transfer_fee.maximum_fee();
+
+        // Hit maximum fee.
+        assert_eq!(transfer_fee.calculate_fee(u64::MAX), Some(maximum_fee));
+        // At exactly the max.
+        assert_eq!(
+            transfer_fee.calculate_fee(maximum_fee * ONE),
+            Some(maximum_fee)
+        );
+        // One token above, normally rounds up, but we're at the max.
+        assert_eq!(
+            transfer_fee.calculate_fee(maximum_fee * ONE + 1),
+            Some(maximum_fee)
+        );
+        // One token below, rounds up to the max.
+        assert_eq!(
+            transfer_fee.calculate_fee(maximum_fee * ONE - 1),
+            Some(maximum_fee)
+        );
+    }
+
+    #[test]
+    fn calculate_fee_min() {
+        let transfer_fee = transfer_fee(5_000, 1);
+        let minimum_fee = 1;
+
+        // Hit minimum fee even with 1 token.
+        assert_eq!(transfer_fee.calculate_fee(1), Some(minimum_fee));
+        // Still minimum at 2 tokens.
+        assert_eq!(transfer_fee.calculate_fee(2), Some(minimum_fee));
+        // Still minimum at 10_000 tokens.
+        assert_eq!(transfer_fee.calculate_fee(ONE), Some(minimum_fee));
+        // 2 token fee at 10_001.
+        assert_eq!(transfer_fee.calculate_fee(ONE + 1), Some(minimum_fee + 1));
+        // Zero is always zero.
+        assert_eq!(transfer_fee.calculate_fee(0), Some(0));
+    }
+
+    #[test]
+    fn calculate_fee_zero() {
+        for transfer_fee in [
+            transfer_fee(u64::MAX, 0),
+            transfer_fee(0, MAX_FEE_BASIS_POINTS),
+        ] {
+            assert_eq!(transfer_fee.calculate_fee(0), Some(0));
+            assert_eq!(transfer_fee.calculate_fee(u64::MAX), Some(0));
+            assert_eq!(transfer_fee.calculate_fee(1), Some(0));
+            assert_eq!(transfer_fee.calculate_fee(ONE), Some(0));
+        }
+    }
+
+    #[test]
+    fn calculate_fee_full() {
+        let transfer_fee = transfer_fee(u64::MAX, MAX_FEE_BASIS_POINTS);
+
+        assert_eq!(transfer_fee.calculate_fee(1), Some(1));
+        assert_eq!(transfer_fee.calculate_fee(u64::MAX), Some(u64::MAX));
+        assert_eq!(transfer_fee.calculate_post_fee_amount(u64::MAX), Some(0));
+    }
+
+    #[test]
+    fn calculate_fee_exact_out_max() {
+        let transfer_fee = transfer_fee(5_000, 1);
+        let maximum_fee = transfer_fee.maximum_fee();
+
+        // Hit maximum fee.
+        assert_eq!(
+            transfer_fee.calculate_inverse_fee(u64::MAX - maximum_fee),
+            Some(maximum_fee)
+        );
+        // At exactly the max.
+        assert_eq!(
+            transfer_fee.calculate_inverse_fee(maximum_fee * ONE - maximum_fee),
+            Some(maximum_fee)
+        );
+        // One token above, normally rounds up, but we're at the max.
+        assert_eq!(
+            transfer_fee.calculate_inverse_fee(maximum_fee * ONE - maximum_fee + 1),
+            Some(maximum_fee)
+        );
+        // One token below, rounds up to the max.
+        assert_eq!(
+            transfer_fee.calculate_inverse_fee(maximum_fee * ONE - maximum_fee - 1),
+            Some(maximum_fee)
+        );
+    }
+
+    #[test]
+    fn calculate_fee_exact_out_min() {
+        let transfer_fee = transfer_fee(5_000, 1);
+        let minimum_fee = 1;
+
+        // Hit minimum fee even with 1 token.
+        assert_eq!(transfer_fee.calculate_inverse_fee(1), Some(minimum_fee));
+        // Still minimum at 2 tokens.
+        assert_eq!(transfer_fee.calculate_inverse_fee(2), Some(minimum_fee));
+        // Still minimum at 9_999 tokens.
+        assert_eq!(
+            transfer_fee.calculate_inverse_fee(ONE - 1),
+            Some(minimum_fee)
+        );
+        // 2 token fee at 10_000.
+        assert_eq!(
+            transfer_fee.calculate_inverse_fee(ONE),
+            Some(minimum_fee + 1)
+        );
+        // Zero is zero token.
+        assert_eq!(transfer_fee.calculate_inverse_fee(0), Some(0));
+    }
+
+    #[test]
+    fn calculate_pre_fee_amount_edge_cases() {
+        let maximum_fee = 5_000;
+
+        let transfer_fee = transfer_fee(maximum_fee, 1);
+        // 0 zero fee.
+        assert_eq!(transfer_fee.calculate_pre_fee_amount(0), Some(0));
+        // Max fee.
+        assert_eq!(
+            transfer_fee.calculate_pre_fee_amount(u64::MAX - maximum_fee),
+            Some(u64::MAX)
+        );
+        // Overflow.
+        assert_eq!(transfer_fee.calculate_pre_fee_amount(u64::MAX), None);
+
+        // 100% fee.
+        let transfer_fee = self::transfer_fee(maximum_fee, MAX_FEE_BASIS_POINTS);
+        assert_eq!(
+            transfer_fee.calculate_pre_fee_amount(1),
+            Some(maximum_fee + 1)
+        );
+
+        // 0% fee.
+        let transfer_fee = self::transfer_fee(maximum_fee, 0);
+        assert_eq!(transfer_fee.calculate_pre_fee_amount(1), Some(1));
+    }
+
+    #[test]
+    fn pre_fee_amount_round_trip() {
+        for transfer_fee_basis_points in [1, 25, 100, 5_000, 9_999] {
+            for maximum_fee in [1, 1_000, 5_000_000, u64::MAX] {
+                let transfer_fee = transfer_fee(maximum_fee, transfer_fee_basis_points);
+
+                for amount_in in [1, 7, 9_999, 10_001, 123_456_789, 1 << 40] {
+                    let amount_out = transfer_fee.calculate_post_fee_amount(amount_in).unwrap();
+
+                    // The whole amount is taken as fee.
+                    if amount_out == 0 {
+                        continue;
+                    }
+
+                    let pre_fee_amount = transfer_fee.calculate_pre_fee_amount(amount_out).unwrap();
+
+                    // The pre-fee amount is the smallest amount that results in
+                    // the same post-fee amount.
+                    assert!(pre_fee_amount <= amount_in);
+                    assert_eq!(
+                        transfer_fee.calculate_post_fee_amount(pre_fee_amount),
+                        Some(amount_out)
+                    );
+                    assert_ne!(
+                        transfer_fee.calculate_post_fee_amount(pre_fee_amount - 1),
+                        Some(amount_out)
+                    );
+                    assert_eq!(
+                        transfer_fee.calculate_inverse_fee(amount_out),
+                        Some(pre_fee_amount - amount_out)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use solana_address::Address;
+
+use super::{optional_address, AccountType, Extension, ExtensionType};
+
+/// Transfer hook extension data for mints.
+#[repr(C)]
+pub struct TransferHook {
+    /// Authority that can set the transfer hook program id.
+    authority: Address,
+
+    /// Program that authorizes the transfer.
+    program_id: Address,
+}
+
+impl Extension for TransferHook {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Mint;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferHook)
+    }
+}
+
+impl TransferHook {
+    /// Optional authority that can set the transfer hook program id.
+    pub fn authority(&self) -> Option<&Address> {
+        optional_address(&self.authority)
+    }
+
+    /// Optional program invoked on every transfer.
+    pub fn program_id(&self) -> Option<&Address> {
+        optional_address(&self.program_id)
+    }
+}
+
+/// Transfer hook extension data for token accounts.
+#[repr(C)]
+pub struct TransferHookAccount {
+    /// Flag to indicate that the account is in the middle of a transfer.
+    transferring: u8,
+}
+
+impl Extension for TransferHookAccount {
+    const TYPE: ExtensionType = ExtensionType::TransferHookAccount;
+
+    const ACCOUNT_TYPE: AccountType = AccountType::Account;
+
+    #[inline(always)]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const TransferHookAccount)
+    }
+}
+
+impl TransferHookAccount {
+    /// Indicates whether the account is in the middle of a transfer.
+    #[inline(always)]
+    pub fn transferring(&self) -> bool {
+        self.transferring != 0
+    }
+}
@@ -0,0 +1,951 @@
+use core::mem::size_of;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+/// Maximum number of seeds of a program derived address.
+const MAX_SEEDS: usize = 16;
+
+/// Maximum length of a seed of a program derived address.
+const MAX_SEED_LEN: usize = 32;
+
+/// Length of the header of an entry of the `ExtraAccountMetaList` TLV data: an
+/// 8-byte discriminator and a 4-byte length.
+const LIST_ENTRY_HEADER_LEN: usize = 12;
+
+/// Extra account required by an instruction of a program implementing an
+/// interface (e.g., the transfer hook `Execute` instruction).
+///
+/// The address of the account is either a literal address, a program derived
+/// address whose seeds are resolved from the instruction, or an address read
+/// from the instruction data or account data.
+#[repr(C)]
+pub struct ExtraAccountMeta {
+    /// Discriminator to tell how to interpret the address config.
+    discriminator: u8,
+
+    /// Either the address of the account or the packed configuration to
+    /// resolve it.
+    address_config: [u8; 32],
+
+    /// Whether the account should sign.
+    is_signer: u8,
+
+    /// Whether the account should be writable.
+    is_writable: u8,
+}
+
+impl ExtraAccountMeta {
+    /// The length of the `ExtraAccountMeta` data.
+    pub const LEN: usize = size_of::<ExtraAccountMeta>();
+
+    /// Discriminator of a literal address.
+    pub const LITERAL: u8 = 0;
+
+    /// Discriminator of a program derived address of the interface program.
+    pub const PDA: u8 = 1;
+
+    /// Discriminator of an address stored in the instruction data or account data.
+    pub const PUBKEY_DATA: u8 = 2;
+
+    /// Discriminator offset of a program derived address of a program provided in
+    /// the instruction accounts. The account index is added to this value.
+    pub const EXTERNAL_PDA: u8 = 1 << 7;
+
+    /// Create an extra account meta with a literal address.
+    #[inline(always)]
+    pub fn new_with_address(address: &Address, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            discriminator: Self::LITERAL,
+            address_config: *address.as_array(),
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        }
+    }
+
+    /// Create an extra account meta with a program derived address of the
+    /// interface program.
+    #[inline(always)]
+    pub fn new_with_seeds(
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            discriminator: Self::PDA,
+            address_config: Seed::pack(seeds)?,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    /// Create an extra account meta with a program derived address of the program
+    /// at `program_index` in the instruction accounts.
+    #[inline(always)]
+    pub fn new_external_pda_with_seeds(
+        program_index: u8,
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ProgramError> {
+        if program_index >= Self::EXTERNAL_PDA {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            discriminator: Self::EXTERNAL_PDA + program_index,
+            address_config: Seed::pack(seeds)?,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    #[inline(always)]
+    pub fn discriminator(&self) -> u8 {
+        self.discriminator
+    }
+
+    #[inline(always)]
+    pub fn address_config(&self) -> &[u8; 32] {
+        &self.address_config
+    }
+
+    #[inline(always)]
+    pub fn is_signer(&self) -> bool {
+        self.is_signer != 0
+    }
+
+    #[inline(always)]
+    pub fn is_writable(&self) -> bool {
+        self.is_writable != 0
+    }
+
+    /// Resolve the address of the extra account.
+    ///
+    /// `instruction_data` and `accounts` are the data and accounts of the instruction
+    /// the extra account is required for, including the extra accounts resolved before
+    /// this one; `program_id` is the address of the interface program.
+    pub fn resolve(
+        &self,
+        instruction_data: &[u8],
+        program_id: &Address,
+        accounts: &[&AccountView],
+    ) -> Result<Address, ProgramError> {
+        match self.discriminator {
+            Self::LITERAL => Ok(Address::new_from_array(self.address_config)),
+            Self::PDA => resolve_pda(&self.address_config, instruction_data, program_id, accounts),
+            Self::PUBKEY_DATA => {
+                resolve_pubkey_data(&self.address_config, instruction_data, accounts)
+            }
+            discriminator if discriminator >= Self::EXTERNAL_PDA => {
+                let program = accounts
+                    .get((discriminator - Self::EXTERNAL_PDA) as usize)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                resolve_pda(
+                    &self.address_config,
+                    instruction_data,
+                    program.address(),
+                    accounts,
+                )
+            }
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Seed of a program derived address resolved from the instruction.
+pub enum Seed<'a> {
+    /// Literal bytes.
+    Literal(&'a [u8]),
+
+    /// Bytes of the instruction data.
+    InstructionData { index: u8, length: u8 },
+
+    /// Address of the account at `index` in the instruction accounts.
+    AccountKey { index: u8 },
+
+    /// Bytes of the data of the account at `account_index` in the instruction accounts.
+    AccountData {
+        account_index: u8,
+        data_index: u8,
+        length: u8,
+    },
+}
+
+impl Seed<'_> {
+    /// Pack the seeds into the address config of an `ExtraAccountMeta`.
+    pub fn pack(seeds: &[Seed]) -> Result<[u8; 32], ProgramError> {
+        let mut address_config = [0; 32];
+        let mut offset = 0;
+
+        for seed in seeds {
+            let packed_len = match seed {
+                Seed::Literal(bytes) => 2 + bytes.len(),
+                Seed::InstructionData { .. } => 3,
+                Seed::AccountKey { .. } => 2,
+                Seed::AccountData { .. } => 4,
+            };
+            let config = address_config
+                .get_mut(offset..offset + packed_len)
+                .ok_or(ProgramError::MaxSeedLengthExceeded)?;
+
+            match *seed {
+                Seed::Literal(bytes) => {
+                    config[0] = 1;
+                    config[1] = bytes.len() as u8;
+                    config[2..].copy_from_slice(bytes);
+                }
+                Seed::InstructionData { index, length } => {
+                    config.copy_from_slice(&[2, index, length]);
+                }
+                Seed::AccountKey { index } => {
+                    config.copy_from_slice(&[3, index]);
+                }
+                Seed::AccountData {
+                    account_index,
+                    data_index,
+                    length,
+                } => {
+                    config.copy_from_slice(&[4, account_index, data_index, length]);
+                }
+            }
+
+            offset += packed_len;
+        }
+
+        Ok(address_config)
+    }
+}
+
+/// List of extra accounts stored in the TLV data of a validation account.
+pub struct ExtraAccountMetaList<'a> {
+    /// The extra account metas of the entry.
+    metas: &'a [ExtraAccountMeta],
+}
+
+impl<'a> ExtraAccountMetaList<'a> {
+    /// Return the `ExtraAccountMetaList` of the instruction with the given discriminator
+    /// from the data of a validation account.
+    pub fn from_account_data(
+        data: &'a [u8],
+        discriminator: &[u8; 8],
+    ) -> Result<Self, ProgramError> {
+        let mut offset = 0;
+
+        while offset + LIST_ENTRY_HEADER_LEN <= data.len() {
+            let entry_discriminator = &data[offset..offset + 8];
+            let len = u32::from_le_bytes([
+                data[offset + 8],
+                data[offset + 9],
+                data[offset + 10],
+                data[offset + 11],
+            ]) as usize;
+            let value = data
+                .get(offset + LIST_ENTRY_HEADER_LEN..)
+                .and_then(|value| value.get(..len))
+                .ok_or(ProgramError::InvalidAccountData)?;
+
+            if entry_discriminator == discriminator {
+                return Self::from_bytes(value);
+            }
+
+            offset += LIST_ENTRY_HEADER_LEN + len;
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    /// Return an `ExtraAccountMetaList` from the value of its TLV entry: a `u32`
+    /// count followed by the extra account metas.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ProgramError> {
+        let count = bytes
+            .get(..4)
+            .map(|count| u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let metas = count
+            .checked_mul(ExtraAccountMeta::LEN)
+            .and_then(|len| bytes.get(4..4 + len))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            // SAFETY: `metas` has exactly `count` entries and `ExtraAccountMeta`
+            // has an alignment of 1 byte.
+            metas: unsafe {
+                core::slice::from_raw_parts(metas.as_ptr() as *const ExtraAccountMeta, count)
+            },
+        })
+    }
+
+    /// Size of the validation account data holding a single list with
+    /// `num_metas` extra account metas.
+    #[inline(always)]
+    pub const fn size_of(num_metas: usize) -> usize {
+        LIST_ENTRY_HEADER_LEN + 4 + num_metas * ExtraAccountMeta::LEN
+    }
+
+    /// Write a list with the given discriminator and extra account metas to the
+    /// data of a validation account.
+    ///
+    /// The data must be exactly `size_of(metas.len())` bytes long.
+    pub fn init(
+        data: &mut [u8],
+        discriminator: &[u8; 8],
+        metas: &[ExtraAccountMeta],
+    ) -> Result<(), ProgramError> {
+        if data.len() != Self::size_of(metas.len()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (header, value) = data.split_at_mut(LIST_ENTRY_HEADER_LEN);
+        header[..8].copy_from_slice(discriminator);
+        header[8..].copy_from_slice(&(value.len() as u32).to_le_bytes());
+
+        let (count, entries) = value.split_at_mut(4);
+        count.copy_from_slice(&(metas.len() as u32).to_le_bytes());
+
+        for (entry, meta) in entries.chunks_exact_mut(ExtraAccountMeta::LEN).zip(metas) {
+            entry[0] = meta.discriminator;
+            entry[1..33].copy_from_slice(&meta.address_config);
+            entry[33] = meta.is_signer;
+            entry[34] = meta.is_writable;
+        }
+
+        Ok(())
+    }
+
+    /// Return the extra account metas.
+    #[inline(always)]
+    pub fn metas(&self) -> &'a [ExtraAccountMeta] {
+        self.metas
+    }
+
+    /// Number of extra account metas.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.metas.len()
+    }
+
+    /// Indicates whether the list is empty.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.metas.is_empty()
+    }
+}
+
+/// Fixed-size buffer holding the seeds of a program derived address.
+///
+/// The seeds are copied, so the account data used as seeds is not borrowed
+/// while deriving the address.
+struct SeedBuffer {
+    /// Bytes of each seed.
+    seeds: [[u8; MAX_SEED_LEN]; MAX_SEEDS],
+
+    /// Length of each seed.
+    lengths: [usize; MAX_SEEDS],
+
+    /// Number of seeds.
+    len: usize,
+}
+
+impl SeedBuffer {
+    #[inline(always)]
+    fn push(&mut self, seed: &[u8]) -> Result<(), ProgramError> {
+        if seed.len() > MAX_SEED_LEN || self.len >= MAX_SEEDS {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
+        self.seeds[self.len][..seed.len()].copy_from_slice(seed);
+        self.lengths[self.len] = seed.len();
+        self.len += 1;
+
+        Ok(())
+    }
+}
+
+/// Resolve a program derived address from packed seed configurations.
+fn resolve_pda(
+    address_config: &[u8; 32],
+    instruction_data: &[u8],
+    program_id: &Address,
+    accounts: &[&AccountView],
+) -> Result<Address, ProgramError> {
+    let mut buffer = SeedBuffer {
+        seeds: [[0; MAX_SEED_LEN]; MAX_SEEDS],
+        lengths: [0; MAX_SEEDS],
+        len: 0,
+    };
+    let mut offset = 0;
+
+    while offset < address_config.len() {
+        let config = &address_config[offset..];
+        let arg = |range: core::ops::Range<usize>| -> Result<&[u8], ProgramError> {
+            config.get(range).ok_or(ProgramError::InvalidAccountData)
+        };
+
+        offset += match config[0] {
+            // Uninitialized: end of the seed configurations.
+            0 => break,
+            // Literal: length, bytes.
+            1 => {
+                let len = arg(1..2)?[0] as usize;
+                buffer.push(arg(2..2 + len)?)?;
+                2 + len
+            }
+            // Instruction data: index, length.
+            2 => {
+                let args = arg(1..3)?;
+                let (index, len) = (args[0] as usize, args[1] as usize);
+                buffer.push(
+                    instruction_data
+                        .get(index..index + len)
+                        .ok_or(ProgramError::InvalidInstructionData)?,
+                )?;
+                3
+            }
+            // Account key: index.
+            3 => {
+                let account = accounts
+                    .get(arg(1..2)?[0] as usize)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                buffer.push(account.address().as_ref())?;
+                2
+            }
+            // Account data: account index, data index, length.
+            4 => {
+                let args = arg(1..4)?;
+                let (index, data_index, len) =
+                    (args[0] as usize, args[1] as usize, args[2] as usize);
+                let account = accounts
+                    .get(index)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                buffer.push(
+                    account
+                        .try_borrow()?
+                        .get(data_index..data_index + len)
+                        .ok_or(ProgramError::AccountDataTooSmall)?,
+                )?;
+                4
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+    }
+
+    let mut seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+
+    for ((seed, bytes), len) in seeds
+        .iter_mut()
+        .zip(buffer.seeds.iter())
+        .zip(buffer.lengths.iter())
+    {
+        *seed = &bytes[..*len];
+    }
+
+    find_program_address(&seeds[..buffer.len], program_id).map(|(address, _)| address)
+}
+
+/// Resolve an address stored in the instruction data or account data.
+fn resolve_pubkey_data(
+    address_config: &[u8; 32],
+    instruction_data: &[u8],
+    accounts: &[&AccountView],
+) -> Result<Address, ProgramError> {
+    match address_config[0] {
+        // Instruction data: index.
+        1 => {
+            let index = address_config[1] as usize;
+            let address = instruction_data
+                .get(index..index + 32)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            Address::try_from(address).map_err(|_| ProgramError::InvalidInstructionData)
+        }
+        // Account data: account index, data index.
+        2 => {
+            let account = accounts
+                .get(address_config[1] as usize)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            let data_index = address_config[2] as usize;
+            let data = account.try_borrow()?;
+            let address = data
+                .get(data_index..data_index + 32)
+                .ok_or(ProgramError::AccountDataTooSmall)?;
+            Address::try_from(address).map_err(|_| ProgramError::InvalidAccountData)
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Find a valid program derived address and its bump seed.
+#[inline(always)]
+pub(crate) fn find_program_address(
+    seeds: &[&[u8]],
+    program_id: &Address,
+) -> Result<(Address, u8), ProgramError> {
+    #[cfg(any(target_os = "solana", target_arch = "bpf"))]
+    {
+        Address::try_find_program_address(seeds, program_id).ok_or(ProgramError::InvalidSeeds)
+    }
+
+    #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+    {
+        core::hint::black_box((seeds, program_id));
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use solana_account_view::AccountView;
+    use solana_address::Address;
+    use solana_program_error::ProgramError;
+
+    use super::{ExtraAccountMeta, ExtraAccountMetaList, Seed};
+    use crate::{state::test_utils::make_account_view, ID};
+
+    const DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    /// Return the packed bytes of an extra account meta.
+    fn pack(meta: &ExtraAccountMeta) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(ExtraAccountMeta::LEN);
+        bytes.push(meta.discriminator());
+        bytes.extend_from_slice(meta.address_config());
+        bytes.push(meta.is_signer() as u8);
+        bytes.push(meta.is_writable() as u8);
+        bytes
+    }
+
+    /// Return an extra account meta with a `PUBKEY_DATA` address config.
+    fn pubkey_data_meta(config: &[u8]) -> ExtraAccountMeta {
+        let mut address_config = [0; 32];
+        address_config[..config.len()].copy_from_slice(config);
+
+        ExtraAccountMeta {
+            discriminator: ExtraAccountMeta::PUBKEY_DATA,
+            address_config,
+            is_signer: 0,
+            is_writable: 1,
+        }
+    }
+
+    #[test]
+    fn pack_seeds() {
+        // Layouts of `Seed::pack_into_address_config` from
+        // `spl-tlv-account-resolution`.
+        let address_config = Seed::pack(&[
+            Seed::Literal(b"seed"),
+            Seed::InstructionData {
+                index: 8,
+                length: 4,
+            },
+            Seed::AccountKey { index: 2 },
+            Seed::AccountData {
+                account_index: 1,
+                data_index: 32,
+                length: 8,
+            },
+        ])
+        .unwrap();
+
+        let mut expected = [0; 32];
+        expected[..15].copy_from_slice(&[
+            1, 4, b's', b'e', b'e', b'd', // literal
+            2, 8, 4, // instruction data
+            3, 2, // account key
+            4, 1, 32, 8, // account data
+        ]);
+        assert_eq!(address_config, expected);
+
+        assert_eq!(Seed::pack(&[]), Ok([0; 32]));
+
+        // A literal seed filling the whole address config.
+        let address_config = Seed::pack(&[Seed::Literal(&[7; 30])]).unwrap();
+        assert_eq!(&address_config[..2], &[1, 30]);
+        assert_eq!(&address_config[2..], &[7; 30]);
+    }
+
+    #[test]
+    fn pack_seeds_too_large() {
+        assert_eq!(
+            Seed::pack(&[Seed::Literal(&[1; 31])]),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+        assert_eq!(
+            Seed::pack(&[
+                Seed::Literal(&[1; 28]),
+                Seed::InstructionData {
+                    index: 0,
+                    length: 4,
+                },
+            ]),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+    }
+
+    #[test]
+    fn new_extra_account_meta() {
+        let address = Address::new_from_array([9; 32]);
+        let meta = ExtraAccountMeta::new_with_address(&address, true, false);
+
+        let mut expected = [0; ExtraAccountMeta::LEN];
+        expected[1..33].fill(9);
+        expected[33] = 1;
+        assert_eq!(pack(&meta), expected);
+
+        let meta = ExtraAccountMeta::new_with_seeds(&[Seed::AccountKey { index: 1 }], false, true)
+            .unwrap();
+
+        let mut expected = [0; ExtraAccountMeta::LEN];
+        expected[..3].copy_from_slice(&[ExtraAccountMeta::PDA, 3, 1]);
+        expected[34] = 1;
+        assert_eq!(pack(&meta), expected);
+
+        let meta = ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[Seed::AccountKey { index: 1 }],
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(meta.discriminator(), ExtraAccountMeta::EXTERNAL_PDA + 5);
+
+        assert_eq!(
+            ExtraAccountMeta::new_external_pda_with_seeds(128, &[], false, false).err(),
+            Some(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            ExtraAccountMeta::new_with_seeds(&[Seed::Literal(&[0; 31])], false, false).err(),
+            Some(ProgramError::MaxSeedLengthExceeded)
+        );
+    }
+
+    #[test]
+    fn resolve_literal() {
+        let address = Address::new_from_array([9; 32]);
+        let meta = ExtraAccountMeta::new_with_address(&address, false, false);
+
+        assert_eq!(meta.resolve(&[], &ID, &[]), Ok(address));
+    }
+
+    #[test]
+    fn resolve_pubkey_data() {
+        let address = Address::new_from_array([9; 32]);
+
+        // Instruction data: index.
+        let mut instruction_data = [0; 40];
+        instruction_data[8..].copy_from_slice(address.as_ref());
+
+        let meta = pubkey_data_meta(&[1, 8]);
+        assert_eq!(
+            meta.resolve(&instruction_data, &ID, &[]),
+            Ok(address.clone())
+        );
+
+        let meta = pubkey_data_meta(&[1, 9]);
+        assert_eq!(
+            meta.resolve(&instruction_data, &ID, &[]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Account data: account index, data index.
+        let mut data = [0; 48];
+        data[16..].copy_from_slice(address.as_ref());
+        let (account, _backing) = make_account_view(Address::default(), ID, false, &data);
+        let accounts: [&AccountView; 1] = [&account];
+
+        let meta = pubkey_data_meta(&[2, 0, 16]);
+        assert_eq!(meta.resolve(&[], &ID, &accounts), Ok(address));
+
+        let meta = pubkey_data_meta(&[2, 0, 17]);
+        assert_eq!(
+            meta.resolve(&[], &ID, &accounts),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+
+        let meta = pubkey_data_meta(&[2, 1, 16]);
+        assert_eq!(
+            meta.resolve(&[], &ID, &accounts),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        // Uninitialized or unknown config.
+        let meta = pubkey_data_meta(&[]);
+        assert_eq!(
+            meta.resolve(&[], &ID, &accounts),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let meta = pubkey_data_meta(&[3]);
+        assert_eq!(
+            meta.resolve(&[], &ID, &accounts),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn resolve_pda_seeds() {
+        // Program derived addresses are only derived on-chain; off-chain the
+        // derivation fails with `InvalidSeeds` once every seed is resolved.
+        let (account, _backing) =
+            make_account_view(Address::new_from_array([3; 32]), ID, false, &[0; 16]);
+        let accounts: [&AccountView; 1] = [&account];
+        let instruction_data = [0; 8];
+
+        let resolve = |seeds: &[Seed]| {
+            ExtraAccountMeta::new_with_seeds(seeds, false, false)
+                .unwrap()
+                .resolve(&instruction_data, &ID, &accounts)
+        };
+
+        // 1: literal.
+        assert_eq!(
+            resolve(&[Seed::Literal(b"seed")]),
+            Err(ProgramError::InvalidSeeds)
+        );
+
+        // 2: instruction data.
+        assert_eq!(
+            resolve(&[Seed::InstructionData {
+                index: 4,
+                length: 4
+            }]),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            resolve(&[Seed::InstructionData {
+                index: 4,
+                length: 5
+            }]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // 3: account key.
+        assert_eq!(
+            resolve(&[Seed::AccountKey { index: 0 }]),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            resolve(&[Seed::AccountKey { index: 1 }]),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        // 4: account data.
+        assert_eq!(
+            resolve(&[Seed::AccountData {
+                account_index: 0,
+                data_index: 8,
+                length: 8
+            }]),
+            Err(ProgramError::InvalidSeeds)
+        );
+        assert_eq!(
+            resolve(&[Seed::AccountData {
+                account_index: 0,
+                data_index: 8,
+                length: 9
+            }]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+        assert_eq!(
+            resolve(&[Seed::AccountData {
+                account_index: 1,
+                data_index: 0,
+                length: 1
+            }]),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        // Seeds are resolved in order: a later invalid seed is still reported.
+        assert_eq!(
+            resolve(&[Seed::Literal(b"seed"), Seed::AccountKey { index: 1 }]),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        // External program derived address: the program account must exist.
+        let meta = ExtraAccountMeta::new_external_pda_with_seeds(
+            1,
+            &[Seed::Literal(b"seed")],
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            meta.resolve(&instruction_data, &ID, &accounts),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+
+    #[test]
+    fn resolve_malformed_pda_config() {
+        let resolve = |config: &[u8]| {
+            let mut address_config = [0; 32];
+            address_config[..config.len()].copy_from_slice(config);
+            ExtraAccountMeta {
+                discriminator: ExtraAccountMeta::PDA,
+                address_config,
+                is_signer: 0,
+                is_writable: 0,
+            }
+            .resolve(&[], &ID, &[])
+        };
+
+        // Unknown seed type.
+        assert_eq!(resolve(&[5]), Err(ProgramError::InvalidAccountData));
+
+        // Literal seed longer than the address config.
+        let mut config = [1; 32];
+        config[1] = 31;
+        assert_eq!(resolve(&config), Err(ProgramError::InvalidAccountData));
+
+        // Instruction data seed missing its length.
+        let mut config = [0; 32];
+        config[..2].copy_from_slice(&[1, 28]);
+        config[30] = 2;
+        assert_eq!(resolve(&config), Err(ProgramError::InvalidAccountData));
+
+        // Unknown discriminator.
+        let meta = ExtraAccountMeta {
+            discriminator: 3,
+            address_config: [0; 32],
+            is_signer: 0,
+            is_writable: 0,
+        };
+        assert_eq!(
+            meta.resolve(&[], &ID, &[]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn list_size() {
+        assert_eq!(ExtraAccountMeta::LEN, 35);
+        assert_eq!(ExtraAccountMetaList::size_of(0), 16);
+        assert_eq!(ExtraAccountMetaList::size_of(4), 16 + 4 * 35);
+    }
+
+    #[test]
+    fn init_and_read_list() {
+        let metas = [
+            ExtraAccountMeta::new_with_address(&Address::new_from_array([1; 32]), false, true),
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal(b"lit_seed"),
+                    Seed::InstructionData {
+                        index: 0,
+                        length: 4,
+                    },
+                    Seed::AccountKey { index: 0 },
+                ],
+                false,
+                true,
+            )
+            .unwrap(),
+        ];
+
+        let mut data = [0; ExtraAccountMetaList::size_of(2)];
+        ExtraAccountMetaList::init(&mut data, &DISCRIMINATOR, &metas).unwrap();
+
+        // TLV layout of `spl-tlv-account-resolution`: discriminator, `u32`
+        // length, then a `PodSlice` with a `u32` count and the metas.
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&DISCRIMINATOR);
+        expected.extend_from_slice(&(4 + 2 * 35u32).to_le_bytes());
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        metas.iter().for_each(|meta| expected.extend(pack(meta)));
+        assert_eq!(&data[..], &expected[..]);
+
+        let list = ExtraAccountMetaList::from_account_data(&data, &DISCRIMINATOR).unwrap();
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+
+        for (meta, expected) in list.metas().iter().zip(metas.iter()) {
+            assert_eq!(pack(meta), pack(expected));
+        }
+
+        // Empty list.
+        let mut data = [0; ExtraAccountMetaList::size_of(0)];
+        ExtraAccountMetaList::init(&mut data, &DISCRIMINATOR, &[]).unwrap();
+        let list = ExtraAccountMetaList::from_account_data(&data, &DISCRIMINATOR).unwrap();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn init_list_with_wrong_length() {
+        let metas = [ExtraAccountMeta::new_with_address(
+            &Address::default(),
+            false,
+            false,
+        )];
+
+        let mut data = [0; ExtraAccountMetaList::size_of(1) - 1];
+        assert_eq!(
+            ExtraAccountMetaList::init(&mut data, &DISCRIMINATOR, &metas),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let mut data = [0; ExtraAccountMetaList::size_of(1) + 1];
+        assert_eq!(
+            ExtraAccountMetaList::init(&mut data, &DISCRIMINATOR, &metas),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn read_list_from_multiple_entries() {
+        let metas = [ExtraAccountMeta::new_with_address(
+            &Address::new_from_array([2; 32]),
+            true,
+            true,
+        )];
+        let other = [0xff; 8];
+
+        let mut data = [0; 2 * ExtraAccountMetaList::size_of(1)];
+        let (first, second) = data.split_at_mut(ExtraAccountMetaList::size_of(1));
+        ExtraAccountMetaList::init(first, &other, &metas).unwrap();
+        ExtraAccountMetaList::init(second, &DISCRIMINATOR, &metas).unwrap();
+
+        let list = ExtraAccountMetaList::from_account_data(&data, &DISCRIMINATOR).unwrap();
+        assert_eq!(list.len(), 1);
+        assert!(list.metas()[0].is_signer());
+    }
+
+    #[test]
+    fn read_malformed_list() {
+        let metas = [ExtraAccountMeta::new_with_address(
+            &Address::default(),
+            false,
+            false,
+        )];
+        let mut data = [0; ExtraAccountMetaList::size_of(1)];
+        ExtraAccountMetaList::init(&mut data, &DISCRIMINATOR, &metas).unwrap();
+
+        // Missing discriminator.
+        assert_eq!(
+            ExtraAccountMetaList::from_account_data(&data, &[0; 8]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // Truncated entry.
+        assert_eq!(
+            ExtraAccountMetaList::from_account_data(&data[..data.len() - 1], &DISCRIMINATOR).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // Count larger than the entry.
+        let mut malformed = data;
+        malformed[12..16].copy_from_slice(&2u32.to_le_bytes());
+        assert_eq!(
+            ExtraAccountMetaList::from_account_data(&malformed, &DISCRIMINATOR).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        // Entry too short for the count.
+        assert_eq!(
+            ExtraAccountMetaList::from_bytes(&[0; 3]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -2,6 +2,8 @@ use solana_account_view::{AccountView, Ref};
 use solana_address::Address;
 use solana_program_error::ProgramError;
 
+use super::{tlv_data, AccountType};
+
 use crate::ID;
 
 /// Mint data.
@@ -38,8 +40,11 @@ impl Mint {
 
     /// Return a `Mint` from the given account view.
     ///
-    /// This method performs owner and length validation on `AccountView`, safe borrowing
-    /// the account data.
+    /// This method performs owner, length and account type validation on `AccountView`,
+    /// safe borrowing the account data.
+    ///
+    /// Accounts with extensions must hold the `AccountType::Mint` discriminator
+    /// after the base state.
     #[inline]
     pub fn from_account_view(account_view: &AccountView) -> Result<Ref<Mint>, ProgramError> {
         if account_view.data_len() < Self::BASE_LEN {
@@ -48,15 +53,18 @@ impl Mint {
         if !account_view.owned_by(&ID) {
             return Err(ProgramError::InvalidAccountOwner);
         }
-        Ok(Ref::map(account_view.try_borrow()?, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+        Ref::try_map(account_view.try_borrow()?, |data| {
+            tlv_data(data, AccountType::Mint)?;
+            // SAFETY: `data` holds at least `Self::BASE_LEN` bytes.
+            Ok(unsafe { Self::from_bytes_unchecked(data) })
+        })
+        .map_err(|(_, e)| e)
     }
 
     /// Return a `Mint` from the given account view.
     ///
-    /// This method performs owner and length validation on `AccountView`, but does not
-    /// perform the borrow check.
+    /// This method performs owner, length and account type validation on `AccountView`,
+    /// but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -72,7 +80,9 @@ impl Mint {
         if account_view.owner() != &ID {
             return Err(ProgramError::InvalidAccountOwner);
         }
-        Ok(Self::from_bytes_unchecked(account_view.borrow_unchecked()))
+        let data = account_view.borrow_unchecked();
+        tlv_data(data, AccountType::Mint)?;
+        Ok(Self::from_bytes_unchecked(data))
     }
 
     /// Return a `Mint` from the given bytes.
@@ -144,3 +154,67 @@ impl Mint {
         &self.freeze_authority
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_address::Address;
+    use solana_program_error::ProgramError;
+
+    use super::Mint;
+    use crate::{
+        state::{test_utils::make_account_view, AccountType, Multisig, ACCOUNT_TYPE_OFFSET},
+        ID,
+    };
+
+    /// Return the data of an initialized mint padded to `N` bytes, holding the
+    /// given account type and a `NonTransferable` extension.
+    fn data_with_extensions<const N: usize>(account_type: AccountType) -> [u8; N] {
+        let mut data = [0; N];
+        data[45] = 1;
+        data[ACCOUNT_TYPE_OFFSET] = account_type as u8;
+        data[ACCOUNT_TYPE_OFFSET + 1..ACCOUNT_TYPE_OFFSET + 5].copy_from_slice(&[9, 0, 0, 0]);
+        data
+    }
+
+    /// Read the data as a `Mint` through both the checked and unchecked views.
+    fn read_mint(data: &[u8]) -> Result<bool, ProgramError> {
+        let (account, _backing) =
+            make_account_view(Address::new_from_array([1; 32]), ID, false, data);
+
+        let checked = Mint::from_account_view(&account)?.is_initialized();
+        let unchecked = unsafe { Mint::from_account_view_unchecked(&account)?.is_initialized() };
+        assert_eq!(checked, unchecked);
+
+        Ok(checked)
+    }
+
+    #[test]
+    fn read_mint_with_extensions() {
+        let mut data = [0; Mint::BASE_LEN];
+        data[45] = 1;
+
+        assert_eq!(read_mint(&data), Ok(true));
+        assert_eq!(
+            read_mint(&data_with_extensions::<170>(AccountType::Mint)),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn reject_token_account_as_mint() {
+        assert_eq!(
+            read_mint(&data_with_extensions::<170>(AccountType::Account)),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn reject_multisig_length() {
+        assert_eq!(
+            read_mint(&data_with_extensions::<{ Multisig::LEN }>(
+                AccountType::Mint
+            )),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
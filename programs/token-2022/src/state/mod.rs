@@ -1,9 +1,16 @@
 mod account_state;
+mod extension;
+mod extra_account_meta;
 mod mint;
 mod multisig;
 mod token;
 
+#[cfg(test)]
+mod test_utils;
+
 pub use account_state::*;
+pub use extension::*;
+pub use extra_account_meta::*;
 pub use mint::*;
 pub use multisig::*;
 pub use token::*;
@@ -2,7 +2,7 @@ use solana_account_view::{AccountView, Ref};
 use solana_address::Address;
 use solana_program_error::ProgramError;
 
-use super::AccountState;
+use super::{tlv_data, AccountState, AccountType};
 
 use crate::ID;
 
@@ -52,8 +52,11 @@ impl TokenAccount {
 
     /// Return a `TokenAccount` from the given account view.
     ///
-    /// This method performs owner and length validation on `AccountView`, safe borrowing
-    /// the account data.
+    /// This method performs owner, length and account type validation on `AccountView`,
+    /// safe borrowing the account data.
+    ///
+    /// Accounts with extensions must hold the `AccountType::Account` discriminator
+    /// after the base state.
     #[inline]
     pub fn from_account_view(
         account_view: &AccountView,
@@ -64,15 +67,18 @@ impl TokenAccount {
         if !account_view.owned_by(&ID) {
             return Err(ProgramError::InvalidAccountData);
         }
-        Ok(Ref::map(account_view.try_borrow()?, |data| unsafe {
-            Self::from_bytes_unchecked(data)
-        }))
+        Ref::try_map(account_view.try_borrow()?, |data| {
+            tlv_data(data, AccountType::Account)?;
+            // SAFETY: `data` holds at least `Self::BASE_LEN` bytes.
+            Ok(unsafe { Self::from_bytes_unchecked(data) })
+        })
+        .map_err(|(_, e)| e)
     }
 
     /// Return a `TokenAccount` from the given account view.
     ///
-    /// This method performs owner and length validation on `AccountView`, but does not
-    /// perform the borrow check.
+    /// This method performs owner, length and account type validation on `AccountView`,
+    /// but does not perform the borrow check.
     ///
     /// # Safety
     ///
@@ -88,7 +94,9 @@ impl TokenAccount {
         if account_view.owner() != &ID {
             return Err(ProgramError::InvalidAccountData);
         }
-        Ok(Self::from_bytes_unchecked(account_view.borrow_unchecked()))
+        let data = account_view.borrow_unchecked();
+        tlv_data(data, AccountType::Account)?;
+        Ok(Self::from_bytes_unchecked(data))
     }
 
     /// Return a `TokenAccount` from the given bytes.
@@ -198,3 +206,69 @@ impl TokenAccount {
         self.state == AccountState::Frozen as u8
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_address::Address;
+    use solana_program_error::ProgramError;
+
+    use super::TokenAccount;
+    use crate::{
+        state::{test_utils::make_account_view, AccountType, Multisig, ACCOUNT_TYPE_OFFSET},
+        ID,
+    };
+
+    /// Return the data of an initialized token account padded to `N` bytes,
+    /// holding the given account type and an `ImmutableOwner` extension.
+    fn data_with_extensions<const N: usize>(account_type: AccountType) -> [u8; N] {
+        let mut data = [0; N];
+        data[108] = 1;
+        data[ACCOUNT_TYPE_OFFSET] = account_type as u8;
+        data[ACCOUNT_TYPE_OFFSET + 1..ACCOUNT_TYPE_OFFSET + 5].copy_from_slice(&[7, 0, 0, 0]);
+        data
+    }
+
+    /// Read the data as a `TokenAccount` through both the checked and unchecked
+    /// views.
+    fn read_token_account(data: &[u8]) -> Result<bool, ProgramError> {
+        let (account, _backing) =
+            make_account_view(Address::new_from_array([1; 32]), ID, false, data);
+
+        let checked = TokenAccount::from_account_view(&account)?.is_initialized();
+        let unchecked =
+            unsafe { TokenAccount::from_account_view_unchecked(&account)?.is_initialized() };
+        assert_eq!(checked, unchecked);
+
+        Ok(checked)
+    }
+
+    #[test]
+    fn read_token_account_with_extensions() {
+        let mut data = [0; TokenAccount::BASE_LEN];
+        data[108] = 1;
+
+        assert_eq!(read_token_account(&data), Ok(true));
+        assert_eq!(
+            read_token_account(&data_with_extensions::<170>(AccountType::Account)),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn reject_mint_as_token_account() {
+        assert_eq!(
+            read_token_account(&data_with_extensions::<170>(AccountType::Mint)),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn reject_multisig_length() {
+        assert_eq!(
+            read_token_account(&data_with_extensions::<{ Multisig::LEN }>(
+                AccountType::Account
+            )),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use solana_program_error::ProgramError;
+
+use crate::{instructions::transfer_hook::EXECUTE_DISCRIMINATOR, state::ExtraAccountMetaList};
+
+/// Instructions of the transfer hook interface, as received by a program
+/// implementing it.
+pub enum TransferHookInstruction<'a> {
+    /// Runs additional transfer logic. Invoked by Token-2022 on every transfer
+    /// of a mint with the `TransferHook` extension.
+    ///
+    /// ### Accounts:
+    ///   0. `[]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The destination account.
+    ///   3. `[]` The source account's owner/delegate.
+    ///   4. `[]` The validation state account.
+    ///   5. `..5+N` `[]` The extra accounts required by the program.
+    Execute {
+        /// Amount of tokens to transfer.
+        amount: u64,
+    },
+
+    /// Initializes the extra account metas on the validation state account.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` The validation state account.
+    ///   1. `[]` The token mint.
+    ///   2. `[SIGNER]` The mint authority.
+    ///   3. `[]` The system program.
+    InitializeExtraAccountMetaList {
+        /// Extra accounts required by the `Execute` instruction.
+        extra_account_metas: ExtraAccountMetaList<'a>,
+    },
+
+    /// Updates the extra account metas on the validation state account.
+    ///
+    /// ### Accounts:
+    ///   0. `[WRITE]` The validation state account.
+    ///   1. `[]` The token mint.
+    ///   2. `[SIGNER]` The mint authority.
+    UpdateExtraAccountMetaList {
+        /// Extra accounts required by the `Execute` instruction.
+        extra_account_metas: ExtraAccountMetaList<'a>,
+    },
+}
+
+impl<'a> TransferHookInstruction<'a> {
+    /// Discriminator of the `InitializeExtraAccountMetaList` instruction.
+    pub const INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+        [43, 34, 13, 49, 167, 88, 235, 235];
+
+    /// Discriminator of the `UpdateExtraAccountMetaList` instruction.
+    pub const UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR: [u8; 8] =
+        [157, 105, 42, 146, 102, 85, 241, 174];
+
+    /// Decode a transfer hook interface instruction from its data.
+    ///
+    /// Instruction data layout:
+    /// -  [0..8]: instruction discriminator (8 bytes, u8)
+    /// -  [8..16]: amount (8 bytes, u64) for `Execute`
+    /// -  [8..]: extra account metas count (4 bytes, u32) followed by the
+    ///    extra account metas for `InitializeExtraAccountMetaList` and
+    ///    `UpdateExtraAccountMetaList`
+    pub fn unpack(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = data
+            .split_at_checked(8)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match discriminator {
+            discriminator if discriminator == EXECUTE_DISCRIMINATOR => {
+                let amount = rest
+                    .get(..8)
+                    .and_then(|amount| amount.try_into().ok())
+                    .map(u64::from_le_bytes)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+
+                Ok(Self::Execute { amount })
+            }
+            discriminator
+                if discriminator == Self::INITIALIZE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR =>
+            {
+                Ok(Self::InitializeExtraAccountMetaList {
+                    extra_account_metas: ExtraAccountMetaList::from_bytes(rest)
+                        .map_err(|_| ProgramError::InvalidInstructionData)?,
+                })
+            }
+            discriminator
+                if discriminator == Self::UPDATE_EXTRA_ACCOUNT_META_LIST_DISCRIMINATOR =>
+            {
+                Ok(Self::UpdateExtraAccountMetaList {
+                    extra_account_metas: ExtraAccountMetaList::from_bytes(rest)
+                        .map_err(|_| ProgramError::InvalidInstructionData)?,
+                })
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
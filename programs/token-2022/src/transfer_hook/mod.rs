@@ -0,0 +1,9 @@
+mod instruction;
+mod processor;
+
+pub use instruction::*;
+pub use processor::*;
+
+/// Seed of the validation state account address of a mint, followed by the
+/// mint address.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
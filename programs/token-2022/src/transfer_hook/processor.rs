@@ -0,0 +1,147 @@
+use pinocchio_system::instructions::{Allocate, Assign};
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_instruction_view::cpi::{Seed, Signer};
+use solana_program_error::{ProgramError, ProgramResult};
+
+use super::EXTRA_ACCOUNT_METAS_SEED;
+use crate::{
+    instructions::transfer_hook::EXECUTE_DISCRIMINATOR,
+    state::{find_program_address, Extension, ExtraAccountMetaList, Mint, TransferHookAccount},
+};
+
+/// Check that the source and destination token accounts of an `Execute`
+/// instruction are in the middle of a transfer.
+///
+/// This ensures the transfer hook program is invoked by Token-2022 during a
+/// transfer and not directly.
+pub fn check_transferring(source: &AccountView, destination: &AccountView) -> ProgramResult {
+    for account in [source, destination] {
+        let transferring = TransferHookAccount::from_account_view(account)?
+            .is_some_and(|transfer_hook_account| transfer_hook_account.transferring());
+
+        if !transferring {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the validation state account is the program derived address of
+/// the mint, returning its bump seed.
+pub fn check_validation_address(
+    validation_state: &AccountView,
+    mint: &AccountView,
+    program_id: &Address,
+) -> Result<u8, ProgramError> {
+    let (address, bump) = find_program_address(
+        &[EXTRA_ACCOUNT_METAS_SEED, mint.address().as_ref()],
+        program_id,
+    )?;
+
+    if validation_state.address() != &address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump)
+}
+
+/// Process an `InitializeExtraAccountMetaList` instruction.
+///
+/// The validation state account is allocated and assigned to the program, so
+/// it must already hold enough lamports to be rent exempt for
+/// `ExtraAccountMetaList::size_of(extra_account_metas.len())` bytes.
+pub fn process_initialize_extra_account_meta_list(
+    program_id: &Address,
+    accounts: &[AccountView],
+    extra_account_metas: &ExtraAccountMetaList,
+) -> ProgramResult {
+    let [validation_state, mint, authority, _system_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_mint_authority(mint, authority)?;
+    let bump = [check_validation_address(
+        validation_state,
+        mint,
+        program_id,
+    )?];
+
+    let seeds = [
+        Seed::from(EXTRA_ACCOUNT_METAS_SEED),
+        Seed::from(mint.address().as_ref()),
+        Seed::from(&bump),
+    ];
+
+    Allocate {
+        account: validation_state,
+        space: ExtraAccountMetaList::size_of(extra_account_metas.len()) as u64,
+    }
+    .invoke_signed(&[Signer::from(&seeds)])?;
+
+    Assign {
+        account: validation_state,
+        owner: program_id,
+    }
+    .invoke_signed(&[Signer::from(&seeds)])?;
+
+    ExtraAccountMetaList::init(
+        &mut validation_state.try_borrow_mut()?,
+        &EXECUTE_DISCRIMINATOR,
+        extra_account_metas.metas(),
+    )
+}
+
+/// Process an `UpdateExtraAccountMetaList` instruction.
+///
+/// The validation state account is resized to fit the new list, so it must
+/// already hold enough lamports to be rent exempt for the new size.
+pub fn process_update_extra_account_meta_list(
+    program_id: &Address,
+    accounts: &[AccountView],
+    extra_account_metas: &ExtraAccountMetaList,
+) -> ProgramResult {
+    let [validation_state, mint, authority, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_mint_authority(mint, authority)?;
+    check_validation_address(validation_state, mint, program_id)?;
+
+    if !validation_state.owned_by(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // The list must have been initialized.
+    ExtraAccountMetaList::from_account_data(
+        &validation_state.try_borrow()?,
+        &EXECUTE_DISCRIMINATOR,
+    )?;
+
+    let len = ExtraAccountMetaList::size_of(extra_account_metas.len());
+
+    if validation_state.data_len() != len {
+        validation_state.resize(len)?;
+    }
+
+    ExtraAccountMetaList::init(
+        &mut validation_state.try_borrow_mut()?,
+        &EXECUTE_DISCRIMINATOR,
+        extra_account_metas.metas(),
+    )
+}
+
+/// Check that `authority` is the mint authority of `mint` and signed the
+/// instruction.
+#[inline(always)]
+fn check_mint_authority(mint: &AccountView, authority: &AccountView) -> ProgramResult {
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match Mint::from_account_view(mint)?.mint_authority() {
+        Some(mint_authority) if mint_authority == authority.address() => Ok(()),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
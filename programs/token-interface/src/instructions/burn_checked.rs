@@ -0,0 +1,67 @@
+use solana_account_view::AccountView;
+use solana_instruction_view::cpi::Signer;
+use solana_program_error::ProgramResult;
+
+use crate::TokenProgram;
+
+/// Burn tokens from an account of either token program.
+///
+/// The token program is selected from the owner of the mint.
+///
+/// ### Accounts:
+///   * Single authority
+///   0. `[WRITE]` The account to burn from.
+///   1. `[WRITE]` The token mint.
+///   2. `[SIGNER]` The account's owner/delegate.
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The account to burn from.
+///   1. `[WRITE]` The token mint.
+///   2. `[]` The account's multisignature owner/delegate.
+///   3. ..3+M `[SIGNER]` M signer accounts.
+pub struct BurnChecked<'a, 'b> {
+    /// Source of the Burn Account
+    pub account: &'a AccountView,
+    /// Mint Account
+    pub mint: &'a AccountView,
+    /// Owner of the Token Account (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'b [&'a AccountView],
+    /// Amount
+    pub amount: u64,
+    /// Decimals
+    pub decimals: u8,
+}
+
+impl BurnChecked<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        match TokenProgram::from_account_view(self.mint)? {
+            TokenProgram::Token => pinocchio_token::instructions::BurnChecked {
+                account: self.account,
+                mint: self.mint,
+                authority: self.authority,
+                signers: self.signers,
+                amount: self.amount,
+                decimals: self.decimals,
+            }
+            .invoke_signed(signers),
+            TokenProgram::Token2022 => pinocchio_token_2022::instructions::BurnChecked {
+                account: self.account,
+                mint: self.mint,
+                authority: self.authority,
+                signers: self.signers,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: &pinocchio_token_2022::ID,
+            }
+            .invoke_signed(signers),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use solana_account_view::AccountView;
+use solana_instruction_view::cpi::Signer;
+use solana_program_error::ProgramResult;
+
+use crate::TokenProgram;
+
+/// Close an account of either token program by transferring all its SOL to the
+/// destination account.
+///
+/// The token program is selected from the owner of the account.
+///
+/// ### Accounts:
+///   * Single authority
+///   0. `[WRITE]` The account to close.
+///   1. `[WRITE]` The destination account.
+///   2. `[SIGNER]` The account's owner.
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The account to close.
+///   1. `[WRITE]` The destination account.
+///   2. `[]` The account's multisignature owner.
+///   3. ..3+M `[SIGNER]` M signer accounts.
+pub struct CloseAccount<'a, 'b> {
+    /// Token Account.
+    pub account: &'a AccountView,
+    /// Destination Account
+    pub destination: &'a AccountView,
+    /// Owner Account (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'b [&'a AccountView],
+}
+
+impl CloseAccount<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        match TokenProgram::from_account_view(self.account)? {
+            TokenProgram::Token => pinocchio_token::instructions::CloseAccount {
+                account: self.account,
+                destination: self.destination,
+                authority: self.authority,
+                signers: self.signers,
+            }
+            .invoke_signed(signers),
+            TokenProgram::Token2022 => pinocchio_token_2022::instructions::CloseAccount {
+                account: self.account,
+                destination: self.destination,
+                authority: self.authority,
+                signers: self.signers,
+                token_program: &pinocchio_token_2022::ID,
+            }
+            .invoke_signed(signers),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use solana_account_view::AccountView;
+use solana_instruction_view::cpi::Signer;
+use solana_program_error::ProgramResult;
+
+use crate::TokenProgram;
+
+/// Mint new tokens to an account of either token program.
+///
+/// The token program is selected from the owner of the mint.
+///
+/// ### Accounts:
+///   * Single authority
+///   0. `[WRITE]` The mint.
+///   1. `[WRITE]` The account to mint tokens to.
+///   2. `[SIGNER]` The mint's minting authority.
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The mint.
+///   1. `[WRITE]` The account to mint tokens to.
+///   2. `[]` The mint's multisignature mint-tokens authority.
+///   3. ..3+M `[SIGNER]` M signer accounts.
+pub struct MintToChecked<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Token Account.
+    pub account: &'a AccountView,
+    /// Mint Authority (single or multisig).
+    pub mint_authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'b [&'a AccountView],
+    /// Amount
+    pub amount: u64,
+    /// Decimals
+    pub decimals: u8,
+}
+
+impl MintToChecked<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        match TokenProgram::from_account_view(self.mint)? {
+            TokenProgram::Token => pinocchio_token::instructions::MintToChecked {
+                mint: self.mint,
+                account: self.account,
+                mint_authority: self.mint_authority,
+                signers: self.signers,
+                amount: self.amount,
+                decimals: self.decimals,
+            }
+            .invoke_signed(signers),
+            TokenProgram::Token2022 => pinocchio_token_2022::instructions::MintToChecked {
+                mint: self.mint,
+                account: self.account,
+                mint_authority: self.mint_authority,
+                signers: self.signers,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: &pinocchio_token_2022::ID,
+            }
+            .invoke_signed(signers),
+        }
+    }
+}
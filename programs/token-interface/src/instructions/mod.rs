@@ -0,0 +1,9 @@
+mod burn_checked;
+mod close_account;
+mod mint_to_checked;
+mod transfer_checked;
+
+pub use burn_checked::*;
+pub use close_account::*;
+pub use mint_to_checked::*;
+pub use transfer_checked::*;
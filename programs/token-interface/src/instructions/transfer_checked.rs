@@ -0,0 +1,73 @@
+use solana_account_view::AccountView;
+use solana_instruction_view::cpi::Signer;
+use solana_program_error::ProgramResult;
+
+use crate::TokenProgram;
+
+/// Transfer tokens from one token account to another of either token program.
+///
+/// The token program is selected from the owner of the mint.
+///
+/// ### Accounts:
+///   * Single authority
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+///
+///   * Multisignature authority
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[]` The source account's multisignature owner/delegate.
+///   4. ..4+M `[SIGNER]` M signer accounts.
+pub struct TransferChecked<'a, 'b> {
+    /// Sender account.
+    pub from: &'a AccountView,
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Recipient account.
+    pub to: &'a AccountView,
+    /// Authority account (single or multisig).
+    pub authority: &'a AccountView,
+    /// Signer accounts if the authority is a multisig.
+    pub signers: &'b [&'a AccountView],
+    /// Amount of micro-tokens to transfer.
+    pub amount: u64,
+    /// Decimal for the Token.
+    pub decimals: u8,
+}
+
+impl TransferChecked<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        match TokenProgram::from_account_view(self.mint)? {
+            TokenProgram::Token => pinocchio_token::instructions::TransferChecked {
+                from: self.from,
+                mint: self.mint,
+                to: self.to,
+                authority: self.authority,
+                signers: self.signers,
+                amount: self.amount,
+                decimals: self.decimals,
+            }
+            .invoke_signed(signers),
+            TokenProgram::Token2022 => pinocchio_token_2022::instructions::TransferChecked {
+                from: self.from,
+                mint: self.mint,
+                to: self.to,
+                authority: self.authority,
+                signers: self.signers,
+                amount: self.amount,
+                decimals: self.decimals,
+                token_program: &pinocchio_token_2022::ID,
+            }
+            .invoke_signed(signers),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+#![no_std]
+
+pub mod instructions;
+pub mod state;
+
+#[cfg(test)]
+mod test_utils;
+
+use solana_account_view::AccountView;
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+/// Token program that owns a mint or token account.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenProgram {
+    /// SPL Token program.
+    Token,
+    /// SPL Token-2022 program.
+    Token2022,
+}
+
+impl TokenProgram {
+    /// Return the token program that owns the given account.
+    ///
+    /// Returns `ProgramError::InvalidAccountOwner` if the account is not owned by
+    /// either token program.
+    #[inline]
+    pub fn from_account_view(account_view: &AccountView) -> Result<Self, ProgramError> {
+        if account_view.owned_by(&pinocchio_token::ID) {
+            Ok(TokenProgram::Token)
+        } else if account_view.owned_by(&pinocchio_token_2022::ID) {
+            Ok(TokenProgram::Token2022)
+        } else {
+            Err(ProgramError::InvalidAccountOwner)
+        }
+    }
+
+    /// Return the address of the token program.
+    #[inline(always)]
+    pub const fn address(self) -> &'static Address {
+        match self {
+            TokenProgram::Token => &pinocchio_token::ID,
+            TokenProgram::Token2022 => &pinocchio_token_2022::ID,
+        }
+    }
+}
+
+/// Call the same method on the view of either token program.
+macro_rules! dispatch {
+    ($self:ident.$method:ident()) => {
+        match $self {
+            Self::Token(inner) => inner.$method(),
+            Self::Token2022(inner) => inner.$method(),
+        }
+    };
+}
+
+pub(crate) use dispatch;
+
+#[cfg(test)]
+mod tests {
+    use solana_address::Address;
+    use solana_program_error::ProgramError;
+
+    use crate::{
+        state::{Mint, TokenAccount},
+        test_utils::make_account_view,
+        TokenProgram,
+    };
+
+    /// Return the data of an initialized mint with the given decimals.
+    fn mint_data(decimals: u8) -> [u8; 82] {
+        let mut data = [0; 82];
+        data[36..44].copy_from_slice(&1_000u64.to_le_bytes());
+        data[44] = decimals;
+        data[45] = 1;
+        data
+    }
+
+    /// Return the data of an initialized token account with the given amount.
+    fn token_account_data(amount: u64) -> [u8; 165] {
+        let mut data = [0; 165];
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        data[108] = 1;
+        data
+    }
+
+    #[test]
+    fn mint_owned_by_token() {
+        let (account, _backing) = make_account_view(&pinocchio_token::ID, &mint_data(6));
+
+        assert_eq!(
+            TokenProgram::from_account_view(&account),
+            Ok(TokenProgram::Token)
+        );
+
+        let mint = Mint::from_account_view(&account).unwrap();
+
+        assert!(matches!(mint, Mint::Token(_)));
+        assert_eq!(mint.token_program().address(), &pinocchio_token::ID);
+        assert_eq!(mint.decimals(), 6);
+        assert_eq!(mint.supply(), 1_000);
+    }
+
+    #[test]
+    fn mint_owned_by_token_2022() {
+        let (account, _backing) = make_account_view(&pinocchio_token_2022::ID, &mint_data(9));
+
+        assert_eq!(
+            TokenProgram::from_account_view(&account),
+            Ok(TokenProgram::Token2022)
+        );
+
+        let mint = Mint::from_account_view(&account).unwrap();
+
+        assert!(matches!(mint, Mint::Token2022(_)));
+        assert_eq!(mint.token_program().address(), &pinocchio_token_2022::ID);
+        assert_eq!(mint.decimals(), 9);
+    }
+
+    #[test]
+    fn token_account_dispatch() {
+        for (owner, token_program) in [
+            (pinocchio_token::ID, TokenProgram::Token),
+            (pinocchio_token_2022::ID, TokenProgram::Token2022),
+        ] {
+            let (account, _backing) = make_account_view(&owner, &token_account_data(42));
+            let token_account = TokenAccount::from_account_view(&account).unwrap();
+
+            assert_eq!(token_account.token_program(), token_program);
+            assert_eq!(token_account.amount(), 42);
+            assert!(token_account.is_initialized());
+        }
+    }
+
+    #[test]
+    fn foreign_owner() {
+        let owner = Address::new_from_array([7; 32]);
+
+        let (account, _backing) = make_account_view(&owner, &mint_data(6));
+
+        assert_eq!(
+            TokenProgram::from_account_view(&account),
+            Err(ProgramError::InvalidAccountOwner)
+        );
+        assert!(matches!(
+            Mint::from_account_view(&account),
+            Err(ProgramError::InvalidAccountOwner)
+        ));
+
+        let (account, _backing) = make_account_view(&owner, &token_account_data(42));
+
+        assert!(matches!(
+            TokenAccount::from_account_view(&account),
+            Err(ProgramError::InvalidAccountOwner)
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+use solana_account_view::{AccountView, Ref};
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+use crate::{dispatch, TokenProgram};
+
+/// Read-only view of a mint of either token program.
+pub enum Mint<'a> {
+    /// Mint owned by the SPL Token program.
+    Token(Ref<'a, pinocchio_token::state::Mint>),
+    /// Mint owned by the SPL Token-2022 program.
+    Token2022(Ref<'a, pinocchio_token_2022::state::Mint>),
+}
+
+impl<'a> Mint<'a> {
+    /// Return a `Mint` from the given account view.
+    ///
+    /// The account is validated by the token program that owns it, safe borrowing
+    /// the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &'a AccountView) -> Result<Self, ProgramError> {
+        match TokenProgram::from_account_view(account_view)? {
+            TokenProgram::Token => {
+                pinocchio_token::state::Mint::from_account_view(account_view).map(Mint::Token)
+            }
+            TokenProgram::Token2022 => {
+                pinocchio_token_2022::state::Mint::from_account_view(account_view)
+                    .map(Mint::Token2022)
+            }
+        }
+    }
+
+    /// Return the token program that owns the mint.
+    #[inline(always)]
+    pub fn token_program(&self) -> TokenProgram {
+        match self {
+            Mint::Token(_) => TokenProgram::Token,
+            Mint::Token2022(_) => TokenProgram::Token2022,
+        }
+    }
+
+    /// Indicates whether the mint authority is present or not.
+    #[inline(always)]
+    pub fn has_mint_authority(&self) -> bool {
+        dispatch!(self.has_mint_authority())
+    }
+
+    /// Return the mint authority, if any.
+    #[inline(always)]
+    pub fn mint_authority(&self) -> Option<&Address> {
+        dispatch!(self.mint_authority())
+    }
+
+    /// Return the total supply of tokens.
+    #[inline(always)]
+    pub fn supply(&self) -> u64 {
+        dispatch!(self.supply())
+    }
+
+    /// Return the number of base 10 digits to the right of the decimal place.
+    #[inline(always)]
+    pub fn decimals(&self) -> u8 {
+        dispatch!(self.decimals())
+    }
+
+    /// Indicates whether the mint has been initialized.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        dispatch!(self.is_initialized())
+    }
+
+    /// Indicates whether the freeze authority is present or not.
+    #[inline(always)]
+    pub fn has_freeze_authority(&self) -> bool {
+        dispatch!(self.has_freeze_authority())
+    }
+
+    /// Return the freeze authority, if any.
+    #[inline(always)]
+    pub fn freeze_authority(&self) -> Option<&Address> {
+        dispatch!(self.freeze_authority())
+    }
+}
@@ -0,0 +1,5 @@
+mod mint;
+mod token;
+
+pub use mint::*;
+pub use token::*;
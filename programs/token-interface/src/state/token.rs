@@ -0,0 +1,114 @@
+use solana_account_view::{AccountView, Ref};
+use solana_address::Address;
+use solana_program_error::ProgramError;
+
+use crate::{dispatch, TokenProgram};
+
+/// Read-only view of a token account of either token program.
+pub enum TokenAccount<'a> {
+    /// Token account owned by the SPL Token program.
+    Token(Ref<'a, pinocchio_token::state::TokenAccount>),
+    /// Token account owned by the SPL Token-2022 program.
+    Token2022(Ref<'a, pinocchio_token_2022::state::TokenAccount>),
+}
+
+impl<'a> TokenAccount<'a> {
+    /// Return a `TokenAccount` from the given account view.
+    ///
+    /// The account is validated by the token program that owns it, safe borrowing
+    /// the account data.
+    #[inline]
+    pub fn from_account_view(account_view: &'a AccountView) -> Result<Self, ProgramError> {
+        match TokenProgram::from_account_view(account_view)? {
+            TokenProgram::Token => {
+                pinocchio_token::state::TokenAccount::from_account_view(account_view)
+                    .map(TokenAccount::Token)
+            }
+            TokenProgram::Token2022 => {
+                pinocchio_token_2022::state::TokenAccount::from_account_view(account_view)
+                    .map(TokenAccount::Token2022)
+            }
+        }
+    }
+
+    /// Return the token program that owns the token account.
+    #[inline(always)]
+    pub fn token_program(&self) -> TokenProgram {
+        match self {
+            TokenAccount::Token(_) => TokenProgram::Token,
+            TokenAccount::Token2022(_) => TokenProgram::Token2022,
+        }
+    }
+
+    /// Return the mint associated with the account.
+    #[inline(always)]
+    pub fn mint(&self) -> &Address {
+        dispatch!(self.mint())
+    }
+
+    /// Return the owner of the account.
+    #[inline(always)]
+    pub fn owner(&self) -> &Address {
+        dispatch!(self.owner())
+    }
+
+    /// Return the amount of tokens the account holds.
+    #[inline(always)]
+    pub fn amount(&self) -> u64 {
+        dispatch!(self.amount())
+    }
+
+    /// Indicates whether the delegate is present or not.
+    #[inline(always)]
+    pub fn has_delegate(&self) -> bool {
+        dispatch!(self.has_delegate())
+    }
+
+    /// Return the delegate, if any.
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<&Address> {
+        dispatch!(self.delegate())
+    }
+
+    /// Indicates whether the account holds wrapped SOL.
+    #[inline(always)]
+    pub fn is_native(&self) -> bool {
+        dispatch!(self.is_native())
+    }
+
+    /// Return the rent-exempt reserve of a native account, if any.
+    #[inline(always)]
+    pub fn native_amount(&self) -> Option<u64> {
+        dispatch!(self.native_amount())
+    }
+
+    /// Return the amount delegated.
+    #[inline(always)]
+    pub fn delegated_amount(&self) -> u64 {
+        dispatch!(self.delegated_amount())
+    }
+
+    /// Indicates whether the close authority is present or not.
+    #[inline(always)]
+    pub fn has_close_authority(&self) -> bool {
+        dispatch!(self.has_close_authority())
+    }
+
+    /// Return the close authority, if any.
+    #[inline(always)]
+    pub fn close_authority(&self) -> Option<&Address> {
+        dispatch!(self.close_authority())
+    }
+
+    /// Indicates whether the account has been initialized.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        dispatch!(self.is_initialized())
+    }
+
+    /// Indicates whether the account is frozen.
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        dispatch!(self.is_frozen())
+    }
+}
@@ -0,0 +1,73 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_instruction_view::{
+    cpi::{get_return_data, invoke},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::{ProgramError, ProgramResult};
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Convert an amount of tokens to its UI amount string, using the decimals
+/// of the mint.
+///
+/// The UI amount is returned by the program through return data.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub struct AmountToUiAmount<'a> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// Amount of tokens to convert.
+    pub amount: u64,
+}
+
+impl AmountToUiAmount<'_> {
+    /// Invoke the instruction, writing the UI amount into `buffer`.
+    ///
+    /// Returns `ProgramError::InvalidArgument` if `buffer` is too small for the
+    /// UI amount, and `ProgramError::InvalidAccountData` if the program did not
+    /// return a valid UI amount.
+    #[inline(always)]
+    pub fn invoke<'c>(&self, buffer: &'c mut [u8]) -> Result<&'c str, ProgramError> {
+        self.invoke_without_return_data()?;
+
+        let return_data = get_return_data()
+            .filter(|return_data| return_data.program_id() == &crate::ID)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let ui_amount = return_data.as_slice();
+
+        let buffer = buffer
+            .get_mut(..ui_amount.len())
+            .ok_or(ProgramError::InvalidArgument)?;
+        buffer.copy_from_slice(ui_amount);
+
+        core::str::from_utf8(buffer).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    fn invoke_without_return_data(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::readonly(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        let mut instruction_data = [UNINIT_BYTE; 9];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[23]);
+        // Set amount as u64 at offset [1..9]
+        write_bytes(&mut instruction_data[1..], &self.amount.to_le_bytes());
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 9) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
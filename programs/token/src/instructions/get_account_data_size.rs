@@ -0,0 +1,50 @@
+use solana_account_view::AccountView;
+use solana_instruction_view::{
+    cpi::{get_return_data, invoke},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::{ProgramError, ProgramResult};
+
+/// Get the required account data size of a token account for the given
+/// mint.
+///
+/// The size is returned by the program through return data.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub struct GetAccountDataSize<'a> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+}
+
+impl GetAccountDataSize<'_> {
+    /// Invoke the instruction, returning the account data size.
+    ///
+    /// Returns `ProgramError::InvalidAccountData` if the program did not return
+    /// a valid size.
+    #[inline(always)]
+    pub fn invoke(&self) -> Result<u64, ProgramError> {
+        self.invoke_without_return_data()?;
+
+        get_return_data()
+            .filter(|return_data| return_data.program_id() == &crate::ID)
+            .and_then(|return_data| return_data.as_slice().try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    fn invoke_without_return_data(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::readonly(self.mint.address())];
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[21],
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
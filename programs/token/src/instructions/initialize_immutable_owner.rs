@@ -0,0 +1,34 @@
+use solana_account_view::AccountView;
+use solana_instruction_view::{cpi::invoke, InstructionAccount, InstructionView};
+use solana_program_error::ProgramResult;
+
+/// Initialize the immutable owner extension on a new token account.
+///
+/// The Token program only checks that the account is not initialized. This
+/// instruction must be invoked before `InitializeAccount`. Use
+/// `pinocchio_token_2022::instructions::InitializeImmutableOwner` for
+/// Token-2022 accounts.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to initialize.
+pub struct InitializeImmutableOwner<'a> {
+    /// Token Account.
+    pub account: &'a AccountView,
+}
+
+impl InitializeImmutableOwner<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::writable(self.account.address())];
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: &[22],
+        };
+
+        invoke(&instruction, &[self.account])
+    }
+}
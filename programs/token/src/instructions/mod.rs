@@ -1,12 +1,15 @@
+mod amount_to_ui_amount;
 mod approve;
 mod approve_checked;
 mod burn;
 mod burn_checked;
 mod close_account;
 mod freeze_account;
+mod get_account_data_size;
 mod initialize_account;
 mod initialize_account_2;
 mod initialize_account_3;
+mod initialize_immutable_owner;
 mod initialize_mint;
 mod initialize_mint_2;
 mod initialize_multisig;
@@ -19,16 +22,20 @@ mod sync_native;
 mod thaw_account;
 mod transfer;
 mod transfer_checked;
+mod ui_amount_to_amount;
 
+pub use amount_to_ui_amount::*;
 pub use approve::*;
 pub use approve_checked::*;
 pub use burn::*;
 pub use burn_checked::*;
 pub use close_account::*;
 pub use freeze_account::*;
+pub use get_account_data_size::*;
 pub use initialize_account::*;
 pub use initialize_account_2::*;
 pub use initialize_account_3::*;
+pub use initialize_immutable_owner::*;
 pub use initialize_mint::*;
 pub use initialize_mint_2::*;
 pub use initialize_multisig::*;
@@ -41,3 +48,4 @@ pub use sync_native::*;
 pub use thaw_account::*;
 pub use transfer::*;
 pub use transfer_checked::*;
+pub use ui_amount_to_amount::*;
@@ -0,0 +1,75 @@
+use core::slice::from_raw_parts;
+
+use solana_account_view::AccountView;
+use solana_instruction_view::{
+    cpi::{get_return_data, invoke},
+    InstructionAccount, InstructionView,
+};
+use solana_program_error::{ProgramError, ProgramResult};
+
+use crate::{write_bytes, UNINIT_BYTE};
+
+/// Maximum length of the UI amount string of a `UiAmountToAmount` instruction.
+pub const MAX_UI_AMOUNT_LEN: usize = 128;
+
+/// Convert a UI amount string to its amount of tokens, using the decimals of
+/// the mint.
+///
+/// The amount is returned by the program through return data.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub struct UiAmountToAmount<'a, 'b> {
+    /// Mint Account.
+    pub mint: &'a AccountView,
+    /// UI amount to convert (at most `MAX_UI_AMOUNT_LEN` bytes).
+    pub ui_amount: &'b str,
+}
+
+impl UiAmountToAmount<'_, '_> {
+    /// Invoke the instruction, returning the amount of tokens.
+    ///
+    /// Returns `ProgramError::InvalidAccountData` if the program did not return
+    /// a valid amount.
+    #[inline(always)]
+    pub fn invoke(&self) -> Result<u64, ProgramError> {
+        self.invoke_without_return_data()?;
+
+        get_return_data()
+            .filter(|return_data| return_data.program_id() == &crate::ID)
+            .and_then(|return_data| return_data.as_slice().try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    fn invoke_without_return_data(&self) -> ProgramResult {
+        let ui_amount = self.ui_amount.as_bytes();
+
+        if ui_amount.len() > MAX_UI_AMOUNT_LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Instruction accounts
+        let instruction_accounts: [InstructionAccount; 1] =
+            [InstructionAccount::readonly(self.mint.address())];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..1+N]: ui_amount (N bytes, UTF-8)
+        let mut instruction_data = [UNINIT_BYTE; 1 + MAX_UI_AMOUNT_LEN];
+
+        // Set discriminator as u8 at offset [0]
+        write_bytes(&mut instruction_data, &[24]);
+        // Set ui_amount as UTF-8 at offset [1..1+N]
+        write_bytes(&mut instruction_data[1..], ui_amount);
+
+        let instruction = InstructionView {
+            program_id: &crate::ID,
+            accounts: &instruction_accounts,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, 1 + ui_amount.len()) },
+        };
+
+        invoke(&instruction, &[self.mint])
+    }
+}
@@ -3,6 +3,9 @@ mod mint;
 mod multisig;
 mod token;
 
+#[cfg(test)]
+mod test_utils;
+
 pub use account_state::*;
 pub use mint::*;
 pub use multisig::*;
@@ -102,4 +102,146 @@ impl Multisig {
     pub fn is_initialized(&self) -> bool {
         self.is_initialized != 0
     }
+
+    /// Verify that the given accounts authorize the `Multisig`.
+    ///
+    /// At least [`Self::required_signers`] distinct signer addresses of the
+    /// `Multisig` must be present in `accounts` and have signed, following the
+    /// same rules as the token program. Repeated accounts are only counted once.
+    ///
+    /// Return the matched signers, where each entry indicates whether the signer
+    /// at the same position in [`Self::signers`] has signed.
+    #[inline]
+    pub fn validate_signers(
+        &self,
+        accounts: &[AccountView],
+    ) -> Result<[bool; MAX_MULTISIG_SIGNERS], ProgramError> {
+        if !self.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        let mut matched = [false; MAX_MULTISIG_SIGNERS];
+        let mut num_signers = 0;
+
+        for account in accounts {
+            for (position, signer) in self.signers().iter().enumerate() {
+                if account.address() == signer && !matched[position] {
+                    if !account.is_signer() {
+                        return Err(ProgramError::MissingRequiredSignature);
+                    }
+                    matched[position] = true;
+                    num_signers += 1;
+                }
+            }
+        }
+
+        if num_signers < self.required_signers() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(matched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use solana_account_view::AccountView;
+    use solana_address::Address;
+    use solana_program_error::ProgramError;
+
+    use super::Multisig;
+    use crate::{instructions::MAX_MULTISIG_SIGNERS, state::test_utils::make_account_view, ID};
+
+    /// Return the data of an `m`-of-`n` multisig whose signer addresses are
+    /// `[1; 32]`, `[2; 32]`, ... `[n; 32]`.
+    fn multisig_data(m: u8, n: u8, is_initialized: bool) -> [u8; Multisig::LEN] {
+        let mut data = [0; Multisig::LEN];
+        data[0] = m;
+        data[1] = n;
+        data[2] = is_initialized as u8;
+
+        for i in 0..n as usize {
+            data[3 + i * 32..3 + (i + 1) * 32].fill(i as u8 + 1);
+        }
+
+        data
+    }
+
+    /// Return signer accounts for the given `(address byte, is_signer)` pairs.
+    ///
+    /// The returned `Vec` holds the account memory and must outlive the views.
+    fn signer_accounts(signers: &[(u8, bool)]) -> (Vec<AccountView>, Vec<Vec<u64>>) {
+        signers
+            .iter()
+            .map(|&(address, is_signer)| {
+                make_account_view(Address::new_from_array([address; 32]), ID, is_signer, &[])
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn validate_m_of_n_signers() {
+        let data = multisig_data(2, 3, true);
+        let multisig = unsafe { Multisig::from_bytes_unchecked(&data) };
+
+        let (accounts, _backing) = signer_accounts(&[(1, true), (9, false), (3, true)]);
+        let matched = multisig.validate_signers(&accounts).unwrap();
+
+        let mut expected = [false; MAX_MULTISIG_SIGNERS];
+        expected[0] = true;
+        expected[2] = true;
+
+        assert_eq!(matched, expected);
+
+        // Not enough signers.
+        let (accounts, _backing) = signer_accounts(&[(2, true)]);
+
+        assert_eq!(
+            multisig.validate_signers(&accounts),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn count_duplicated_signer_once() {
+        let data = multisig_data(2, 3, true);
+        let multisig = unsafe { Multisig::from_bytes_unchecked(&data) };
+
+        let (accounts, _backing) = signer_accounts(&[(1, true), (1, true)]);
+
+        assert_eq!(
+            multisig.validate_signers(&accounts),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn reject_matching_key_without_signature() {
+        let data = multisig_data(2, 3, true);
+        let multisig = unsafe { Multisig::from_bytes_unchecked(&data) };
+
+        let (accounts, _backing) = signer_accounts(&[(1, true), (2, false), (3, true)]);
+
+        assert_eq!(
+            multisig.validate_signers(&accounts),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+    }
+
+    #[test]
+    fn reject_uninitialized_multisig() {
+        let data = multisig_data(1, 3, false);
+        let multisig = unsafe { Multisig::from_bytes_unchecked(&data) };
+
+        let (accounts, _backing) = signer_accounts(&[(1, true)]);
+
+        assert_eq!(
+            multisig.validate_signers(&accounts),
+            Err(ProgramError::UninitializedAccount)
+        );
+    }
 }
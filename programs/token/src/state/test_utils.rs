@@ -0,0 +1,50 @@
+//! Shared helpers for state tests.
+//!
+//! This module is compiled only when `cfg(test)` is active.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::{mem::size_of, ptr};
+
+use solana_account_view::{AccountView, RuntimeAccount, NOT_BORROWED};
+use solana_address::Address;
+
+/// Allocate a heap-backed `AccountView` whose data region is initialized with
+/// `data`.
+///
+/// The returned `Vec` holds the account memory and must outlive the
+/// `AccountView`.
+pub fn make_account_view(
+    address: Address,
+    owner: Address,
+    is_signer: bool,
+    data: &[u8],
+) -> (AccountView, Vec<u64>) {
+    let total = size_of::<RuntimeAccount>() + data.len();
+    let mut backing = alloc::vec![0u64; total.div_ceil(8)];
+    let raw = backing.as_mut_ptr() as *mut RuntimeAccount;
+
+    // SAFETY: `backing` is 8-byte aligned and large enough to hold the
+    // `RuntimeAccount` header followed by `data`.
+    unsafe {
+        ptr::write(
+            raw,
+            RuntimeAccount {
+                borrow_state: NOT_BORROWED,
+                is_signer: is_signer as u8,
+                address,
+                owner,
+                data_len: data.len() as u64,
+                ..Default::default()
+            },
+        );
+        ptr::copy_nonoverlapping(
+            data.as_ptr(),
+            (raw as *mut u8).add(size_of::<RuntimeAccount>()),
+            data.len(),
+        );
+
+        (AccountView::new_unchecked(raw), backing)
+    }
+}